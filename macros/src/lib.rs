@@ -0,0 +1,101 @@
+//! Procedural macros for `embedded-graphics-colorcast`.
+//!
+//! This crate is re-exported by `embedded-graphics-colorcast` when the
+//! `macros` feature is enabled and shouldn't be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::path::PathBuf;
+use syn::{
+    Error, Ident, LitInt, LitStr, Token,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+struct Input {
+    path: LitStr,
+    threshold: u8,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut threshold = 128;
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match key.to_string().as_str() {
+                "threshold" => threshold = input.parse::<LitInt>()?.base10_parse()?,
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        "unknown option, expected `threshold`",
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { path, threshold })
+    }
+}
+
+/// Convert an image file to 1bpp data at compile time.
+///
+/// The path is relative to the `CARGO_MANIFEST_DIR` of the crate invoking
+/// the macro. PNG and BMP files are supported. Pixels with a BT.601 luma of
+/// at least `threshold` (default `128`) become `BinaryColor::On`, darker and
+/// transparent pixels become `BinaryColor::Off`.
+///
+/// The macro expands to an `ImageRaw<'static, BinaryColor>` expression.
+#[proc_macro]
+pub fn include_binary_image(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &Input) -> syn::Result<proc_macro2::TokenStream> {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::new(Span::call_site(), "CARGO_MANIFEST_DIR is not set"))?;
+    let path = root.join(input.path.value());
+
+    let image = image::open(&path)
+        .map_err(|err| Error::new(input.path.span(), format!("failed to load {path:?}: {err}")))?
+        .into_rgba8();
+
+    let (width, height) = image.dimensions();
+    let stride = width.div_ceil(8) as usize;
+    let mut data = vec![0u8; stride * height as usize];
+    for (x, y, pixel) in image.enumerate_pixels() {
+        let [r, g, b, a] = pixel.0;
+        let luma = (u32::from(r) * 299 + u32::from(g) * 587 + u32::from(b) * 114) / 1000;
+        if a >= 128 && luma >= u32::from(input.threshold) {
+            data[y as usize * stride + x as usize / 8] |= 0x80 >> (x % 8);
+        }
+    }
+
+    let len = data.len();
+    let path = path
+        .to_str()
+        .ok_or_else(|| Error::new(input.path.span(), "path is not valid UTF-8"))?;
+
+    Ok(quote! {
+        {
+            // Rebuild when the image changes.
+            const _: &[u8] = include_bytes!(#path);
+            static DATA: [u8; #len] = [#(#data),*];
+            ::embedded_graphics_colorcast::__private::ImageRaw::<
+                ::embedded_graphics_colorcast::__private::BinaryColor,
+            >::new(&DATA, #width)
+        }
+    })
+}
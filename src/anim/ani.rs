@@ -0,0 +1,237 @@
+//! Multi-frame 1bpp animations.
+//!
+//! The container starts with a 4 byte [`MAGIC`], followed by the width,
+//! height and number of frames as little endian `u16`. Every frame starts
+//! with its duration in milliseconds as little endian `u16` and a kind byte,
+//! `0` for a key frame and `1` for an XOR delta against the previous frame.
+//! The pixel data follows with the layout of
+//! [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw). The first
+//! frame has to be a key frame.
+
+use super::Frame;
+use crate::source::BinaryBuffer;
+use embedded_graphics::prelude::Size;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Magic bytes at the start of every animation
+pub const MAGIC: [u8; 4] = *b"ANI1";
+
+const HEADER_LEN: usize = MAGIC.len() + 6;
+const FRAME_HEADER_LEN: usize = 3;
+const KIND_KEY: u8 = 0;
+const KIND_DELTA: u8 = 1;
+
+/// Errors returned when reading or writing animations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The data doesn't start with [`MAGIC`]
+    BadMagic,
+    /// The data ends before all frames have been described
+    Truncated,
+    /// A frame has an unknown kind, or the first frame is a delta
+    BadFormat,
+    /// The animation is too large for the container format
+    TooLarge,
+}
+
+/// Frame of an [`AnimatedImage`] with its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimedFrame<'a> {
+    /// Time to show the frame, in milliseconds
+    pub duration: u16,
+    /// Pixel data of the frame
+    pub frame: Frame<'a>,
+}
+
+/// Parsed animation container.
+///
+/// Play it back with an [`Animator`](super::Animator).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimatedImage<'a> {
+    frames: &'a [u8],
+    count: usize,
+    size: Size,
+}
+
+impl<'a> AnimatedImage<'a> {
+    /// Parse an animation
+    ///
+    /// All frames are checked once, so reading them afterwards can't fail.
+    pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        let header = data.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(Error::BadMagic);
+        }
+
+        let u16_at = |i: usize| u16::from_le_bytes([header[i], header[i + 1]]);
+        let size = Size::new(u16_at(4).into(), u16_at(6).into());
+        let count = usize::from(u16_at(8));
+
+        let image = Self {
+            frames: &data[HEADER_LEN..],
+            count,
+            size,
+        };
+        let len = count
+            .checked_mul(image.record_len())
+            .ok_or(Error::TooLarge)?;
+        if image.frames.len() < len {
+            return Err(Error::Truncated);
+        }
+        for index in 0..count {
+            match (index, image.record(index)[2]) {
+                (_, KIND_KEY) | (1.., KIND_DELTA) => (),
+                _ => return Err(Error::BadFormat),
+            }
+        }
+
+        Ok(image)
+    }
+
+    /// Returns the size of every frame
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the number of frames
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if the animation doesn't contain any frames
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the frame at `index`, or `None` if it's out of range
+    pub fn frame(&self, index: usize) -> Option<TimedFrame<'a>> {
+        if index >= self.count {
+            return None;
+        }
+
+        let record = self.record(index);
+        let data = &record[FRAME_HEADER_LEN..];
+        Some(TimedFrame {
+            duration: u16::from_le_bytes([record[0], record[1]]),
+            frame: match record[2] {
+                KIND_KEY => Frame::Key(data),
+                _ => Frame::Delta(data),
+            },
+        })
+    }
+
+    /// Returns an iterator over all frames
+    pub fn frames(&self) -> impl Iterator<Item = TimedFrame<'a>> + '_ {
+        (0..self.count).filter_map(|index| self.frame(index))
+    }
+
+    const fn record_len(&self) -> usize {
+        FRAME_HEADER_LEN + BinaryBuffer::<&[u8]>::data_len(self.size.width, self.size.height)
+    }
+
+    fn record(&self, index: usize) -> &'a [u8] {
+        let len = self.record_len();
+        &self.frames[index * len..][..len]
+    }
+}
+
+/// Encode binary images as an animation
+///
+/// Every frame is stored with its duration in milliseconds. With `deltas`,
+/// all frames except the first are stored as XOR deltas, which compress well
+/// when the animation is stored in an [`AssetPack`](crate::source::AssetPack)
+/// or another compressed container. All frames need to be the size of the
+/// first one.
+#[cfg(feature = "std")]
+pub fn encode(frames: &[(u16, &BinaryBuffer<Vec<u8>>)], deltas: bool) -> Result<Vec<u8>, Error> {
+    use embedded_graphics::geometry::OriginDimensions;
+
+    let size = frames
+        .first()
+        .map_or(Size::zero(), |(_, image)| image.size());
+    let width = u16::try_from(size.width).map_err(|_| Error::TooLarge)?;
+    let height = u16::try_from(size.height).map_err(|_| Error::TooLarge)?;
+    let count = u16::try_from(frames.len()).map_err(|_| Error::TooLarge)?;
+    if frames.iter().any(|(_, image)| image.size() != size) {
+        return Err(Error::BadFormat);
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+
+    let mut previous: Option<&[u8]> = None;
+    for (duration, image) in frames {
+        out.extend_from_slice(&duration.to_le_bytes());
+        match previous.filter(|_| deltas) {
+            Some(previous) => {
+                out.push(KIND_DELTA);
+                out.extend(super::delta(previous, image.data()));
+            }
+            None => {
+                out.push(KIND_KEY);
+                out.extend_from_slice(image.data());
+            }
+        }
+        previous = Some(image.data());
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [u8; 18] = [
+        b'A',
+        b'N',
+        b'I',
+        b'1',
+        4,
+        0,
+        1,
+        0,
+        2,
+        0, //
+        100,
+        0,
+        KIND_KEY,
+        0b1000_0000, //
+        50,
+        0,
+        KIND_DELTA,
+        0b1100_0000,
+    ];
+
+    #[test]
+    fn test_parse() {
+        let image = AnimatedImage::new(&DATA).unwrap();
+        assert_eq!(image.size(), Size::new(4, 1));
+        assert_eq!(image.len(), 2);
+        assert_eq!(
+            image.frame(1),
+            Some(TimedFrame {
+                duration: 50,
+                frame: Frame::Delta(&[0b1100_0000]),
+            })
+        );
+
+        assert_eq!(AnimatedImage::new(&DATA[..17]), Err(Error::Truncated));
+        let mut delta_first = DATA;
+        delta_first[12] = KIND_DELTA;
+        assert_eq!(AnimatedImage::new(&delta_first), Err(Error::BadFormat));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_encode_roundtrip() {
+        let first = BinaryBuffer::new(std::vec![0b1000_0000], 4, 1).unwrap();
+        let second = BinaryBuffer::new(std::vec![0b0100_0000], 4, 1).unwrap();
+        let data = encode(&[(100, &first), (50, &second)], true).unwrap();
+        assert_eq!(data, DATA);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::source::BinaryBuffer;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Pixel data of a single animation frame.
+///
+/// The data uses the same layout as
+/// [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw): MSB-first
+/// with byte-aligned rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frame<'a> {
+    /// Complete frame
+    Key(&'a [u8]),
+    /// XOR delta against the previous frame
+    ///
+    /// Typical UI animations only change a few pixels between frames, so the
+    /// deltas are mostly zeros and compress well.
+    Delta(&'a [u8]),
+}
+
+impl<'a> Frame<'a> {
+    const fn data(&self) -> &'a [u8] {
+        match self {
+            Frame::Key(data) | Frame::Delta(data) => data,
+        }
+    }
+}
+
+/// Sequence of binary frames of the same size.
+///
+/// Frames can either be stored completely or as XOR deltas against the
+/// previous frame, which are decoded on the fly when a pixel is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Animation<'a> {
+    frames: &'a [Frame<'a>],
+    size: Size,
+}
+
+impl<'a> Animation<'a> {
+    /// Create a new animation
+    ///
+    /// Returns `None` if the first frame is a delta or any frame is too short
+    /// for the given size.
+    pub fn new(frames: &'a [Frame<'a>], width: u32, height: u32) -> Option<Self> {
+        let len = BinaryBuffer::<&[u8]>::data_len(width, height);
+        let valid = !matches!(frames.first(), Some(Frame::Delta(_)))
+            && frames.iter().all(|frame| frame.data().len() >= len);
+
+        valid.then_some(Self {
+            frames,
+            size: Size::new(width, height),
+        })
+    }
+
+    /// Returns the number of frames
+    pub const fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the animation doesn't contain any frames
+    pub const fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the frame at `index`, or `None` if it's out of range
+    pub fn frame(&self, index: usize) -> Option<AnimationFrame<'a>> {
+        self.frames.get(index)?;
+
+        // Decoding starts at the closest key frame.
+        let key = self.frames[..=index]
+            .iter()
+            .rposition(|frame| matches!(frame, Frame::Key(_)))
+            .unwrap_or(0);
+
+        Some(AnimationFrame {
+            frames: &self.frames[key..=index],
+            size: self.size,
+        })
+    }
+
+    /// Returns an iterator over all frames
+    pub fn frames(&self) -> impl Iterator<Item = AnimationFrame<'a>> + '_ {
+        (0..self.len()).filter_map(|index| self.frame(index))
+    }
+}
+
+/// Single decoded frame of an [`Animation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AnimationFrame<'a> {
+    /// Key frame followed by the deltas up to this frame
+    frames: &'a [Frame<'a>],
+    size: Size,
+}
+
+impl OriginDimensions for AnimationFrame<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for AnimationFrame<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let mut color = BinaryColor::Off;
+        for frame in self.frames {
+            let bit =
+                BinaryBuffer::new(frame.data(), self.size.width, self.size.height)?.pixel(p)?;
+            if bit.is_on() {
+                color = color.invert();
+            }
+        }
+        Some(color)
+    }
+}
+
+/// Compute the XOR delta between two frames
+///
+/// The result can be stored as a [`Frame::Delta`] following `previous`.
+#[cfg(feature = "std")]
+pub fn delta(previous: &[u8], next: &[u8]) -> Vec<u8> {
+    previous.iter().zip(next).map(|(a, b)| a ^ b).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animation_deltas() {
+        let frames = [
+            Frame::Key(&[0b1000_0000]),
+            Frame::Delta(&[0b1100_0000]),
+            Frame::Delta(&[0b0110_0000]),
+            Frame::Key(&[0b0001_0000]),
+        ];
+        let animation = Animation::new(&frames, 4, 1).unwrap();
+        assert_eq!(animation.len(), 4);
+
+        let on: [u8; 4] = core::array::from_fn(|i| {
+            let frame = animation.frame(i).unwrap();
+            (0..4).fold(0, |acc, x| {
+                (acc << 1) | u8::from(frame.pixel(Point::new(x, 0)) == Some(BinaryColor::On))
+            })
+        });
+        assert_eq!(on, [0b1000, 0b0100, 0b0010, 0b0001]);
+        assert!(animation.frame(4).is_none());
+    }
+
+    #[test]
+    fn test_animation_invalid() {
+        assert!(Animation::new(&[Frame::Delta(&[0])], 8, 1).is_none());
+        assert!(Animation::new(&[Frame::Key(&[0])], 9, 1).is_none());
+    }
+}
@@ -0,0 +1,178 @@
+use super::{Frame, ani::AnimatedImage};
+use crate::source::BinaryBuffer;
+use embedded_graphics::geometry::OriginDimensions;
+
+/// Plays back an [`AnimatedImage`] into a frame buffer.
+///
+/// The current frame is decoded into the buffer, so XOR deltas only have to
+/// be applied once. The animation loops after the last frame.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{
+///     Image,
+///     anim::{Animator, ani::AnimatedImage},
+///     source::BinaryBuffer,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let data = [
+///     b'A', b'N', b'I', b'1', 4, 0, 1, 0, 2, 0,
+///     100, 0, 0, 0b1000_0000,
+///     100, 0, 1, 0b1100_0000,
+/// ];
+/// let animation = AnimatedImage::new(&data).unwrap();
+/// let buffer = BinaryBuffer::new([0; 1], 4, 1).unwrap();
+/// let mut animator = Animator::new(animation, buffer).unwrap();
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// if animator.update(120) {
+///     Image::new(animator.frame(), Point::zero(), Rgb565::WHITE).draw(&mut display)?;
+/// }
+/// assert_eq!(animator.index(), 1);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Animator<'a, B> {
+    animation: AnimatedImage<'a>,
+    buffer: BinaryBuffer<B>,
+    index: usize,
+    elapsed: u32,
+}
+
+impl<'a, B> Animator<'a, B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Start playing an animation at its first frame
+    ///
+    /// Returns `None` if the animation is empty or the buffer doesn't have
+    /// the size of its frames.
+    pub fn new(animation: AnimatedImage<'a>, buffer: BinaryBuffer<B>) -> Option<Self> {
+        if animation.is_empty() || buffer.size() != animation.size() {
+            return None;
+        }
+
+        let mut animator = Self {
+            animation,
+            buffer,
+            index: 0,
+            elapsed: 0,
+        };
+        animator.apply(0);
+        Some(animator)
+    }
+
+    /// Returns the current frame
+    pub const fn frame(&self) -> &BinaryBuffer<B> {
+        &self.buffer
+    }
+
+    /// Returns the index of the current frame
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Advance the animation by `elapsed` milliseconds
+    ///
+    /// Returns `true` if the current frame changed and needs to be drawn.
+    pub fn update(&mut self, elapsed: u32) -> bool {
+        self.elapsed = self.elapsed.saturating_add(elapsed);
+
+        let mut changed = false;
+        loop {
+            let duration = self.duration();
+            if self.elapsed < duration {
+                break;
+            }
+            self.elapsed -= duration;
+            self.advance();
+            changed = true;
+
+            // Frames without a duration are shown for a single update.
+            if duration == 0 {
+                break;
+            }
+        }
+        changed
+    }
+
+    /// Jump back to the first frame
+    pub fn restart(&mut self) {
+        self.index = 0;
+        self.elapsed = 0;
+        self.apply(0);
+    }
+
+    /// Returns the frame buffer, consuming the animator
+    pub fn into_inner(self) -> BinaryBuffer<B> {
+        self.buffer
+    }
+
+    fn duration(&self) -> u32 {
+        self.animation
+            .frame(self.index)
+            .map_or(0, |frame| frame.duration.into())
+    }
+
+    fn advance(&mut self) {
+        self.index = (self.index + 1) % self.animation.len();
+        self.apply(self.index);
+    }
+
+    fn apply(&mut self, index: usize) {
+        let Some(frame) = self.animation.frame(index) else {
+            return;
+        };
+
+        let data = self.buffer.data_mut();
+        match frame.frame {
+            Frame::Key(key) => data.copy_from_slice(key),
+            Frame::Delta(delta) => data.iter_mut().zip(delta).for_each(|(a, b)| *a ^= b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animator_loops() {
+        let data = [
+            b'A',
+            b'N',
+            b'I',
+            b'1',
+            2,
+            0,
+            1,
+            0,
+            3,
+            0, //
+            10,
+            0,
+            0,
+            0b1000_0000, //
+            10,
+            0,
+            1,
+            0b1100_0000, //
+            10,
+            0,
+            0,
+            0b1100_0000,
+        ];
+        let animation = AnimatedImage::new(&data).unwrap();
+        let buffer = BinaryBuffer::new([0; 1], 2, 1).unwrap();
+        let mut animator = Animator::new(animation, buffer).unwrap();
+        assert_eq!(animator.frame().data(), [0b1000_0000]);
+
+        assert!(!animator.update(9));
+        assert!(animator.update(1));
+        assert_eq!(animator.frame().data(), [0b0100_0000]);
+        assert!(animator.update(25));
+        assert_eq!(animator.index(), 0);
+        assert_eq!(animator.frame().data(), [0b1000_0000]);
+    }
+}
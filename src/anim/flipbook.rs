@@ -0,0 +1,87 @@
+use crate::source::BinaryBuffer;
+
+/// Animation made of separate arrays of raw frames.
+///
+/// This is the minimal animation container for firmware that already has
+/// an array per frame. All frames use the layout of
+/// [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw) and have the
+/// same size.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Image, anim::FlipBook};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// static FRAMES: [&[u8]; 2] = [&[0b1000_0000], &[0b0100_0000]];
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let book = FlipBook::new(&FRAMES, 2, 1).unwrap();
+///
+/// let frame = book.frame(1).unwrap();
+/// Image::new(&frame, Point::zero(), Rgb565::WHITE).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlipBook<'a> {
+    frames: &'a [&'a [u8]],
+    width: u32,
+    height: u32,
+}
+
+impl<'a> FlipBook<'a> {
+    /// Create a new flip book
+    ///
+    /// Returns `None` if any frame is too short for the given size.
+    pub fn new(frames: &'a [&'a [u8]], width: u32, height: u32) -> Option<Self> {
+        let len = BinaryBuffer::<&[u8]>::data_len(width, height);
+        frames
+            .iter()
+            .all(|frame| frame.len() >= len)
+            .then_some(Self {
+                frames,
+                width,
+                height,
+            })
+    }
+
+    /// Returns the number of frames
+    pub const fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if the flip book doesn't contain any frames
+    pub const fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the frame at `index`, or `None` if it's out of range
+    pub fn frame(&self, index: usize) -> Option<BinaryBuffer<&'a [u8]>> {
+        BinaryBuffer::new(*self.frames.get(index)?, self.width, self.height)
+    }
+
+    /// Returns an iterator over all frames
+    pub fn frames(&self) -> impl Iterator<Item = BinaryBuffer<&'a [u8]>> + '_ {
+        (0..self.len()).filter_map(|index| self.frame(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::Point};
+
+    #[test]
+    fn test_flip_book() {
+        let frames: [&[u8]; 3] = [&[0x80, 0x00], &[0x00, 0x80], &[0x40, 0x00]];
+        let book = FlipBook::new(&frames, 2, 2).unwrap();
+        assert_eq!(book.len(), 3);
+        assert_eq!(
+            book.frame(1).unwrap().pixel(Point::new(0, 1)),
+            Some(BinaryColor::On)
+        );
+        assert!(book.frame(3).is_none());
+        assert_eq!(book.frames().count(), 3);
+
+        assert!(FlipBook::new(&frames, 2, 3).is_none());
+    }
+}
@@ -0,0 +1,12 @@
+//! Animation containers for binary images.
+
+pub mod ani;
+mod animation;
+mod animator;
+mod flipbook;
+
+#[cfg(feature = "std")]
+pub use self::animation::delta;
+pub use self::animation::{Animation, AnimationFrame, Frame};
+pub use self::animator::Animator;
+pub use self::flipbook::FlipBook;
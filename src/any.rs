@@ -0,0 +1,116 @@
+use crate::Image;
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, PointsIter},
+    primitives::Rectangle,
+};
+
+/// Tinted image with the source type erased.
+///
+/// This trait is dyn compatible, so images with different source types can
+/// be stored in a single list and drawn uniformly.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{AnyTintedImage, Image, source::AdafruitBitmap};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+/// let bitmap = AdafruitBitmap::new(&[0xf0], 4, 1);
+///
+/// let logo = Image::new(&raw, Point::zero(), Rgb565::RED);
+/// let icon = Image::new(&bitmap, Point::new(0, 2), Rgb565::GREEN);
+/// let screen: [&dyn AnyTintedImage<Rgb565>; 2] = [&logo, &icon];
+///
+/// for image in screen {
+///     image.draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+pub trait AnyTintedImage<C: PixelColor> {
+    /// Returns the area covered by the image
+    fn area(&self) -> Rectangle;
+
+    /// Returns the color drawn at `point`, or `None` if it's skipped
+    fn color_at(&self, point: Point) -> Option<C>;
+
+    /// Returns `true` if every pixel of the area is drawn
+    ///
+    /// Opaque images completely hide whatever is below them.
+    fn is_opaque(&self) -> bool {
+        false
+    }
+}
+
+impl<T, C> AnyTintedImage<C> for Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn area(&self) -> Rectangle {
+        self.bounding_box()
+    }
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        let point = point - self.position;
+        self.image
+            .bounding_box()
+            .contains(point)
+            .then(|| self.pixel_at(point))?
+    }
+
+    fn is_opaque(&self) -> bool {
+        Image::is_opaque(self)
+    }
+}
+
+impl<C: PixelColor> Dimensions for dyn AnyTintedImage<C> + '_ {
+    fn bounding_box(&self) -> Rectangle {
+        self.area()
+    }
+}
+
+impl<C: PixelColor> Drawable for dyn AnyTintedImage<C> + '_ {
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(
+            self.area()
+                .points()
+                .filter_map(|point| Some(Pixel(point, self.color_at(point)?))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        image::ImageRaw, mock_display::MockDisplay, pixelcolor::Rgb565, prelude::RgbColor,
+    };
+
+    #[test]
+    fn test_any_matches_image() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0100_0000], 3);
+        let image = Image::new(&raw, Point::new(1, 2), Rgb565::RED).with_background(Rgb565::BLUE);
+
+        let mut expected = MockDisplay::new();
+        image.draw(&mut expected).unwrap();
+
+        let any: &dyn AnyTintedImage<Rgb565> = &image;
+        let mut display = MockDisplay::new();
+        any.draw(&mut display).unwrap();
+        display.assert_eq(&expected);
+        assert_eq!(any.bounding_box(), image.bounding_box());
+        assert_eq!(any.color_at(Point::new(0, 0)), None);
+        assert!(any.is_opaque());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Image;
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PointsIter},
+};
+
+/// Raster operation used to combine an image with a monochrome target.
+///
+/// The source value of each pixel is the image's color for `On` pixels and
+/// its inverse for `Off` pixels. The background is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// Replace the target pixel
+    #[default]
+    Copy,
+    /// Set target pixels where the source is `On`
+    Or,
+    /// Clear target pixels where the source is `Off`
+    And,
+    /// Invert target pixels where the source is `On`
+    Xor,
+    /// Clear target pixels where the source is `On`
+    Clear,
+}
+
+impl BlendMode {
+    /// Combine a target pixel with a source pixel
+    pub const fn apply(self, target: BinaryColor, source: BinaryColor) -> BinaryColor {
+        let (target, source) = (target.is_on(), source.is_on());
+        let on = match self {
+            BlendMode::Copy => source,
+            BlendMode::Or => target | source,
+            BlendMode::And => target & source,
+            BlendMode::Xor => target ^ source,
+            BlendMode::Clear => target & !source,
+        };
+        if on {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        }
+    }
+}
+
+impl<T> Image<'_, T, BinaryColor>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Draw the image by combining it with the existing target pixels
+    ///
+    /// Only pixels that change are sent to the target. Pixels outside the
+    /// target are skipped.
+    pub fn draw_blended<D>(&self, target: &mut D, mode: BlendMode) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = BinaryColor> + GetPixel<Color = BinaryColor>,
+    {
+        for point in self.image.bounding_box().points() {
+            let Some(source) = self.source_pixel(point) else {
+                continue;
+            };
+            let source = match source {
+                BinaryColor::On => self.color,
+                BinaryColor::Off => self.color.invert(),
+            };
+
+            // The target can't be read while `draw_iter` borrows it, so
+            // every changed pixel is sent on its own.
+            let point = self.position + point;
+            if let Some(current) = target.pixel(point) {
+                let color = mode.apply(current, source);
+                if color != current {
+                    target.draw_iter(core::iter::once(Pixel(point, color)))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        framebuffer::{Framebuffer, buffer_size},
+        image::ImageRaw,
+        pixelcolor::raw::{BigEndian, RawU1},
+        prelude::{Point, Transform},
+    };
+
+    type Display =
+        Framebuffer<BinaryColor, RawU1, BigEndian, 8, 1, { buffer_size::<BinaryColor>(8, 1) }>;
+
+    #[test]
+    fn test_draw_blended() {
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 4);
+
+        for (mode, expected) in [
+            (BlendMode::Copy, 0b1010_0000),
+            (BlendMode::Or, 0b1110_0000),
+            (BlendMode::And, 0b1000_0000),
+            (BlendMode::Xor, 0b0110_0000),
+            (BlendMode::Clear, 0b0100_0000),
+        ] {
+            let mut display = Display::new();
+            display.data_mut()[0] = 0b1100_0000;
+
+            let image = Image::new(&image_raw, Point::zero(), BinaryColor::On);
+            image.draw_blended(&mut display, mode).unwrap();
+            image
+                .translate(Point::new(8, 0))
+                .draw_blended(&mut display, mode)
+                .unwrap();
+            assert_eq!(display.data()[0], expected, "{mode:?}");
+        }
+    }
+}
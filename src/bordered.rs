@@ -0,0 +1,129 @@
+use crate::Image;
+use embedded_graphics::{
+    Drawable,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, Primitive, Transform},
+    primitives::{PrimitiveStyleBuilder, Rectangle, StrokeAlignment},
+};
+
+/// Image with a rectangular border around it.
+///
+/// The border is drawn outside of the image, so the bounding box grows by the
+/// border thickness on every side.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Bordered, Image, bitmap};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let icon = bitmap!["#"];
+///
+/// let image = Image::new(&icon, Point::new(1, 1), Rgb565::RED);
+/// Bordered::new(image, 1, Rgb565::BLUE).draw(&mut display)?;
+/// display.assert_pattern(&["BBB", "BRB", "BBB"]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Bordered<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    thickness: u32,
+    color: C,
+}
+
+impl<'a, T, C> Bordered<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Draw a border with the given thickness and color around an image
+    pub const fn new(image: Image<'a, T, C>, thickness: u32, color: C) -> Self {
+        Self {
+            image,
+            thickness,
+            color,
+        }
+    }
+
+    /// Returns the wrapped image
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+}
+
+impl<T, C> Drawable for Bordered<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(self.color)
+            .stroke_width(self.thickness)
+            .stroke_alignment(StrokeAlignment::Outside)
+            .build();
+        self.image.bounding_box().into_styled(style).draw(target)?;
+        self.image.draw(target)
+    }
+}
+
+impl<T, C> Dimensions for Bordered<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box().offset(self.thickness as i32)
+    }
+}
+
+impl<T, C> Transform for Bordered<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            image: self.image.translate(by),
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.image.translate_mut(by);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, pixelcolor::Rgb565, prelude::RgbColor};
+
+    #[test]
+    fn test_bordered_bounding_box() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xff, 0xff], 8);
+        let image = Image::new(&raw, Point::new(4, 4), Rgb565::RED);
+        let bordered = Bordered::new(image, 2, Rgb565::GREEN);
+
+        assert_eq!(
+            bordered.bounding_box(),
+            Rectangle::new(
+                Point::new(2, 2),
+                embedded_graphics::prelude::Size::new(12, 6)
+            )
+        );
+    }
+}
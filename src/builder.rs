@@ -0,0 +1,295 @@
+//! Asset conversion for build scripts.
+//!
+//! These functions convert PNG and BMP files to the 1bpp formats supported by
+//! this crate and emit Rust source code that can be `include!`d by firmware.
+//!
+//! ```rust,no_run
+//! use embedded_graphics_colorcast::builder::{self, Format};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let code = builder::convert("assets/logo.png", "LOGO", Format::Rle, 128)?;
+//! let out_dir = std::env::var("OUT_DIR")?;
+//! std::fs::write(std::path::Path::new(&out_dir).join("logo.rs"), code)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Many images can also be bundled into a single [`pack`] blob with an
+//! [`AssetPackBuilder`].
+
+use crate::source::{BinaryBuffer, pack, rle};
+use core::fmt::{self, Write};
+use embedded_graphics::geometry::OriginDimensions;
+use std::{path::Path, string::String, vec::Vec};
+
+/// Output format of a converted image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Packed pixel data for [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw)
+    Raw,
+    /// [`rle`] container for [`RleImage`](crate::source::RleImage)
+    Rle,
+}
+
+/// Errors returned by the asset conversion functions.
+#[derive(Debug)]
+pub enum Error {
+    /// The image file couldn't be loaded
+    Image(image::ImageError),
+    /// The image couldn't be encoded
+    Rle(rle::Error),
+    /// The asset pack couldn't be written
+    Pack(pack::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Image(err) => write!(f, "failed to load image: {err}"),
+            Error::Rle(err) => write!(f, "failed to encode image: {err}"),
+            Error::Pack(err) => write!(f, "failed to write asset pack: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<image::ImageError> for Error {
+    fn from(err: image::ImageError) -> Self {
+        Error::Image(err)
+    }
+}
+
+impl From<rle::Error> for Error {
+    fn from(err: rle::Error) -> Self {
+        Error::Rle(err)
+    }
+}
+
+impl From<pack::Error> for Error {
+    fn from(err: pack::Error) -> Self {
+        Error::Pack(err)
+    }
+}
+
+/// Load an image file and convert it to a binary image
+///
+/// See [`BinaryBuffer::from_image`] for how pixels are thresholded.
+pub fn load(path: impl AsRef<Path>, threshold: u8) -> Result<BinaryBuffer<Vec<u8>>, Error> {
+    Ok(BinaryBuffer::open(path, threshold)?)
+}
+
+/// Encode a binary image in the given format
+pub fn encode(image: &BinaryBuffer<Vec<u8>>, format: Format) -> Result<Vec<u8>, Error> {
+    match format {
+        Format::Raw => Ok(image.data().to_vec()),
+        Format::Rle => Ok(rle::encode(image)?),
+    }
+}
+
+/// Emit Rust source for an encoded image
+///
+/// The output defines a `static` byte array called `name` and `const`s for
+/// the image dimensions, named `{name}_WIDTH` and `{name}_HEIGHT`.
+pub fn rust_source(
+    name: &str,
+    image: &BinaryBuffer<Vec<u8>>,
+    format: Format,
+) -> Result<String, Error> {
+    let data = encode(image, format)?;
+    let size = image.size();
+
+    let mut out = String::new();
+    // Writing to a `String` can't fail.
+    let _ = writeln!(out, "pub const {name}_WIDTH: u32 = {};", size.width);
+    let _ = writeln!(out, "pub const {name}_HEIGHT: u32 = {};", size.height);
+    let _ = writeln!(out, "pub static {name}: [u8; {}] = [", data.len());
+    for chunk in data.chunks(12) {
+        out.push_str("   ");
+        for byte in chunk {
+            let _ = write!(out, " 0x{byte:02x},");
+        }
+        out.push('\n');
+    }
+    out.push_str("];\n");
+
+    Ok(out)
+}
+
+/// Load an image file and emit Rust source for it in one step
+///
+/// See [`load`] and [`rust_source`].
+pub fn convert(
+    path: impl AsRef<Path>,
+    name: &str,
+    format: Format,
+    threshold: u8,
+) -> Result<String, Error> {
+    let image = load(path, threshold)?;
+    rust_source(name, &image, format)
+}
+
+/// Builder for [`pack`] blobs.
+///
+/// ```rust,no_run
+/// use embedded_graphics_colorcast::builder::{AssetPackBuilder, Format};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let pack = AssetPackBuilder::new()
+///     .add_file("logo", "assets/logo.png", Format::Rle, 128)?
+///     .add_file("wifi", "assets/wifi.png", Format::Raw, 128)?
+///     .build()?;
+/// std::fs::write("assets.pak", pack)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AssetPackBuilder {
+    assets: Vec<(pack::Entry, Vec<u8>)>,
+    crc: bool,
+}
+
+impl AssetPackBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a checksum of every asset, see [`pack::FLAG_CRC`]
+    pub fn with_crc(&mut self, crc: bool) -> &mut Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Add a binary image under the given name
+    pub fn add(
+        &mut self,
+        name: &str,
+        image: &BinaryBuffer<Vec<u8>>,
+        format: Format,
+    ) -> Result<&mut Self, Error> {
+        let data = encode(image, format)?;
+        let entry = pack::Entry {
+            hash: pack::name_hash(name),
+            offset: 0,
+            len: data.len(),
+            size: image.size(),
+            format: match format {
+                Format::Raw => pack::FORMAT_RAW,
+                Format::Rle => pack::FORMAT_RLE,
+            },
+        };
+        // Make sure the entry can be serialized before accepting it.
+        entry.to_bytes()?;
+        self.assets.push((entry, data));
+        Ok(self)
+    }
+
+    /// Load an image file and add it under the given name
+    ///
+    /// See [`load`] for how pixels are thresholded.
+    pub fn add_file(
+        &mut self,
+        name: &str,
+        path: impl AsRef<Path>,
+        format: Format,
+        threshold: u8,
+    ) -> Result<&mut Self, Error> {
+        let image = load(path, threshold)?;
+        self.add(name, &image, format)
+    }
+
+    /// Serialize the asset pack
+    pub fn build(&self) -> Result<Vec<u8>, Error> {
+        let count = u16::try_from(self.assets.len()).map_err(|_| pack::Error::TooLarge)?;
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&pack::MAGIC);
+        out.extend_from_slice(&count.to_le_bytes());
+        let flags = if self.crc { pack::FLAG_CRC } else { 0 };
+        out.extend_from_slice(&flags.to_le_bytes());
+
+        let mut offset = pack::HEADER_LEN + self.assets.len() * pack::ENTRY_LEN;
+        if self.crc {
+            offset += self.assets.len() * 4;
+        }
+        for (entry, data) in &self.assets {
+            let entry = pack::Entry { offset, ..*entry };
+            out.extend_from_slice(&entry.to_bytes()?);
+            offset += data.len();
+        }
+        if self.crc {
+            for (_, data) in &self.assets {
+                out.extend_from_slice(&crate::source::crc32(data).to_le_bytes());
+            }
+        }
+        for (_, data) in &self.assets {
+            out.extend_from_slice(data);
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_raw() {
+        let code = convert(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/checker.png"),
+            "CHECKER",
+            Format::Raw,
+            128,
+        )
+        .unwrap();
+        assert_eq!(
+            code,
+            "pub const CHECKER_WIDTH: u32 = 4;\n\
+             pub const CHECKER_HEIGHT: u32 = 2;\n\
+             pub static CHECKER: [u8; 2] = [\n    0xa0, 0x50,\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_asset_pack_roundtrip() {
+        use crate::source::AssetPack;
+        use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::Point};
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/checker.png");
+        let data = AssetPackBuilder::new()
+            .with_crc(true)
+            .add_file("raw", path, Format::Raw, 128)
+            .unwrap()
+            .add_file("rle", path, Format::Rle, 128)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let pack = AssetPack::new(&data).unwrap();
+        assert_eq!(pack.len(), 2);
+        let raw = pack.find("raw").unwrap();
+        let rle = pack.find("rle").unwrap();
+        assert!(matches!(rle, crate::source::Asset::Rle(_)));
+        for x in 0..4 {
+            for y in 0..2 {
+                let p = Point::new(x, y);
+                let expected = BinaryColor::from((x + y) % 2 == 0);
+                assert_eq!(raw.pixel(p), Some(expected));
+                assert_eq!(rle.pixel(p), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_display() {
+        use std::string::ToString;
+
+        let err = Error::Pack(pack::Error::Rle(rle::Error::Truncated));
+        assert_eq!(
+            err.to_string(),
+            "failed to write asset pack: invalid RLE asset: RLE data is truncated"
+        );
+    }
+}
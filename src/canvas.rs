@@ -0,0 +1,34 @@
+use crate::Image;
+use embedded_canvas::Canvas;
+use embedded_graphics::{
+    geometry::OriginDimensions, image::GetPixel, pixelcolor::BinaryColor, prelude::PixelColor,
+};
+
+impl<T, C> Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Draw the image directly into the pixels of a [`Canvas`]
+    ///
+    /// ```rust
+    /// use embedded_canvas::Canvas;
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     pixelcolor::{BinaryColor, Rgb565},
+    ///     prelude::*,
+    /// };
+    /// use embedded_graphics_colorcast::Image;
+    ///
+    /// let mut canvas = Canvas::new(Size::new(16, 8));
+    ///
+    /// let raw = ImageRaw::<BinaryColor>::new(&[0xff, 0x81, 0xff], 8);
+    /// Image::new(&raw, Point::new(4, 2), Rgb565::RED).draw_canvas(&mut canvas);
+    /// assert_eq!(canvas.pixels[2 * 16 + 4], Some(Rgb565::RED));
+    /// ```
+    pub fn draw_canvas(&self, canvas: &mut Canvas<C>) {
+        for (index, color) in self.indexed_pixels(canvas.canvas) {
+            canvas.pixels[index] = Some(color);
+        }
+    }
+}
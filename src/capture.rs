@@ -0,0 +1,121 @@
+use crate::source::BinaryBuffer;
+use core::convert::Infallible;
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, Point, Size},
+};
+
+/// Draw target that records binary drawing into a [`BinaryBuffer`].
+///
+/// Expensive compositions, like a card with lots of text, can be rendered
+/// into a `CaptureTarget` once. The capture is also an image source, so it
+/// can be drawn repeatedly with [`Image`](crate::Image) in any color.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     mono_font::{MonoTextStyle, ascii::FONT_6X10},
+///     pixelcolor::{BinaryColor, Rgb565},
+///     prelude::*,
+///     text::{Baseline, Text},
+/// };
+/// use embedded_graphics_colorcast::{CaptureTarget, Image, source::BinaryBuffer};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let buffer = BinaryBuffer::new([0; 20], 12, 10).unwrap();
+/// let mut capture = CaptureTarget::new(buffer);
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+/// Text::with_baseline("Hi", Point::zero(), style, Baseline::Top).draw(&mut capture)?;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// Image::new(&capture, Point::zero(), Rgb565::RED).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CaptureTarget<B> {
+    buffer: BinaryBuffer<B>,
+}
+
+impl<B> CaptureTarget<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Record drawing into the given buffer
+    pub const fn new(buffer: BinaryBuffer<B>) -> Self {
+        Self { buffer }
+    }
+
+    /// Returns the captured image
+    pub const fn buffer(&self) -> &BinaryBuffer<B> {
+        &self.buffer
+    }
+
+    /// Returns the captured image, consuming the target
+    pub fn into_inner(self) -> BinaryBuffer<B> {
+        self.buffer
+    }
+}
+
+impl<B> From<CaptureTarget<B>> for BinaryBuffer<B> {
+    fn from(capture: CaptureTarget<B>) -> Self {
+        capture.buffer
+    }
+}
+
+impl<B> OriginDimensions for CaptureTarget<B> {
+    fn size(&self) -> Size {
+        self.buffer.size()
+    }
+}
+
+impl<B> GetPixel for CaptureTarget<B>
+where
+    B: AsRef<[u8]>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        self.buffer.pixel(p)
+    }
+}
+
+impl<B> DrawTarget for CaptureTarget<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.buffer.set_pixel(point, color);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        Drawable,
+        prelude::Primitive,
+        primitives::{PrimitiveStyle, Rectangle},
+    };
+
+    #[test]
+    fn test_capture_rectangle() {
+        let mut capture = CaptureTarget::new(BinaryBuffer::new([0u8; 4], 10, 2).unwrap());
+        Rectangle::new(Point::new(7, 1), Size::new(5, 5))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut capture)
+            .unwrap();
+        assert_eq!(capture.into_inner().data(), &[0x00, 0x00, 0x01, 0xc0]);
+    }
+}
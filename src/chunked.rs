@@ -0,0 +1,113 @@
+use crate::Image;
+use core::task::Poll;
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point},
+};
+
+/// Image drawing that can be split across multiple calls.
+///
+/// Created by [`Image::draw_chunked`]. Every call to [`ChunkedDraw::poll`]
+/// processes a bounded number of source pixels and returns
+/// [`Poll::Pending`] until the whole image was drawn. This bounds the time
+/// spent in a single scheduler tick on an RTOS.
+///
+/// ```rust
+/// use core::task::Poll;
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::Image;
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+///
+/// let image = Image::new(&raw, Point::zero(), Rgb565::RED);
+/// let mut draw = image.draw_chunked();
+/// while draw.poll(&mut display, 16)?.is_pending() {
+///     // yield to other tasks
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct ChunkedDraw<'i, 'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: &'i Image<'a, T, C>,
+    next: u64,
+}
+
+impl<'i, 'a, T, C> ChunkedDraw<'i, 'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    pub(crate) const fn new(image: &'i Image<'a, T, C>) -> Self {
+        Self { image, next: 0 }
+    }
+
+    /// Draw the next `budget` source pixels
+    ///
+    /// Returns `Poll::Ready` once all pixels have been processed.
+    pub fn poll<D>(&mut self, target: &mut D, budget: usize) -> Result<Poll<()>, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let size = self.image.image.size();
+        let total = u64::from(size.width) * u64::from(size.height);
+        let end = total.min(self.next.saturating_add(budget as u64));
+
+        let image = self.image;
+        let width = u64::from(size.width);
+        target.draw_iter((self.next..end).filter_map(|index| {
+            let point = Point::new((index % width) as i32, (index / width) as i32);
+            image
+                .pixel_at(point)
+                .map(|color| Pixel(image.position + point, color))
+        }))?;
+
+        self.next = end;
+        Ok(if end == total {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        })
+    }
+
+    /// Returns `true` if the whole image has been drawn
+    pub fn is_done(&self) -> bool {
+        let size = self.image.image.size();
+        self.next >= u64::from(size.width) * u64::from(size.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        Drawable, image::ImageRaw, mock_display::MockDisplay, pixelcolor::Rgb565, prelude::RgbColor,
+    };
+
+    #[test]
+    fn test_chunked_matches_draw() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0110_0000, 0b1100_0000], 3);
+        let image = Image::new(&raw, Point::new(2, 1), Rgb565::RED).with_background(Rgb565::BLUE);
+
+        let mut expected = MockDisplay::new();
+        image.draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        let mut draw = image.draw_chunked();
+        let mut polls = 1;
+        while draw.poll(&mut display, 4).unwrap().is_pending() {
+            polls += 1;
+        }
+        assert_eq!(polls, 3);
+        assert!(draw.is_done());
+        display.assert_eq(&expected);
+    }
+}
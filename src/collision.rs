@@ -0,0 +1,182 @@
+use crate::source::BinaryBuffer;
+use embedded_graphics::{
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, PointsIter, Size},
+    primitives::Rectangle,
+};
+
+/// Bit-packed collision shape, built once from a binary image.
+///
+/// Overlap tests compare eight pixels at a time, independent of how the image
+/// is stored or drawn. A mask can be dilated to give sprites some margin.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics_colorcast::{CollisionMask, bitmap};
+///
+/// let ball = bitmap![" # ", "###", " # "];
+/// let ball = CollisionMask::new(&ball, [0; 3]).unwrap();
+///
+/// assert!(ball.overlaps(&ball, Point::new(1, 1)));
+/// assert!(!ball.overlaps(&ball, Point::new(2, 2)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollisionMask<B> {
+    mask: BinaryBuffer<B>,
+}
+
+impl<B> CollisionMask<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Number of bytes needed for a mask of the given size
+    pub const fn data_len(width: u32, height: u32) -> usize {
+        BinaryBuffer::<B>::data_len(width, height)
+    }
+
+    /// Build a mask from the pixels of `source` that are on
+    ///
+    /// Returns `None` if `buffer` is too short for the size of the source.
+    pub fn new<S>(source: &S, buffer: B) -> Option<Self>
+    where
+        S: OriginDimensions + GetPixel<Color = BinaryColor>,
+    {
+        Self::dilated(source, 0, buffer)
+    }
+
+    /// Build a mask from `source`, grown by `radius` pixels in every direction
+    ///
+    /// The mask is `2 * radius` pixels wider and higher than the source, so
+    /// its top left corner is `radius` pixels above and left of the source.
+    /// Returns `None` if `buffer` is too short for that size, or if the size
+    /// overflows `u32`.
+    pub fn dilated<S>(source: &S, radius: u32, buffer: B) -> Option<Self>
+    where
+        S: OriginDimensions + GetPixel<Color = BinaryColor>,
+    {
+        let margin = radius.checked_mul(2)?;
+        let size = source.size();
+        let width = size.width.checked_add(margin)?;
+        let height = size.height.checked_add(margin)?;
+        let mut mask = BinaryBuffer::new(buffer, width, height)?;
+        mask.data_mut().fill(0);
+
+        let reach = Size::new_equal(margin.checked_add(1)?);
+        for point in source.bounding_box().points() {
+            if source.pixel(point) != Some(BinaryColor::On) {
+                continue;
+            }
+            for offset in Rectangle::new(Point::zero(), reach).points() {
+                mask.set_pixel(point + offset, BinaryColor::On);
+            }
+        }
+
+        Some(Self { mask })
+    }
+}
+
+impl<B> CollisionMask<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Returns `true` if the pixel at `point` is solid
+    pub fn contains(&self, point: Point) -> bool {
+        self.mask.pixel(point) == Some(BinaryColor::On)
+    }
+
+    /// Returns `true` if any solid pixels overlap with `other` placed at `offset`
+    ///
+    /// `offset` is the top left corner of `other` relative to this mask.
+    pub fn overlaps<O>(&self, other: &CollisionMask<O>, offset: Point) -> bool
+    where
+        O: AsRef<[u8]>,
+    {
+        let (size, other_size) = (self.mask.size(), other.mask.size());
+        let top = offset.y.max(0);
+        let bottom = (size.height as i32).min(offset.y + other_size.height as i32);
+        let left = offset.x.max(0);
+        let right = (size.width as i32).min(offset.x + other_size.width as i32);
+        if top >= bottom || left >= right {
+            return false;
+        }
+
+        let (stride, other_stride) = (
+            size.width.div_ceil(8) as usize,
+            other_size.width.div_ceil(8) as usize,
+        );
+        (top..bottom).any(|y| {
+            let row = &self.mask.data()[y as usize * stride..][..stride];
+            let other_y = (y - offset.y) as usize;
+            let other_row = &other.mask.data()[other_y * other_stride..][..other_stride];
+
+            // Padding bits are always clear, so whole bytes can be compared.
+            (left as usize / 8..(right as usize).div_ceil(8))
+                .any(|index| row[index] & byte_at(other_row, index as i32 * 8 - offset.x) != 0)
+        })
+    }
+}
+
+/// Returns the eight bits of `row` starting at bit `start`, with zeros outside the row
+fn byte_at(row: &[u8], start: i32) -> u8 {
+    let index = start.div_euclid(8);
+    let shift = start.rem_euclid(8);
+    let get = |index: i32| {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| row.get(index))
+            .map_or(0, |&byte| u16::from(byte))
+    };
+    (((get(index) << 8 | get(index + 1)) << shift) >> 8) as u8
+}
+
+impl<B> OriginDimensions for CollisionMask<B> {
+    fn size(&self) -> Size {
+        self.mask.size()
+    }
+}
+
+impl<B> GetPixel for CollisionMask<B>
+where
+    B: AsRef<[u8]>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        self.mask.pixel(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::image::ImageRaw;
+
+    #[test]
+    fn test_overlap_at_offsets() {
+        // Two single pixels in column 9 of a 12 pixel wide mask.
+        let raw = ImageRaw::<BinaryColor>::new(&[0x00, 0x40, 0x00, 0x00], 12);
+        let a = CollisionMask::new(&raw, [0; 4]).unwrap();
+        let dot = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let b = CollisionMask::new(&dot, [0; 1]).unwrap();
+
+        assert!(a.overlaps(&b, Point::new(9, 0)));
+        assert!(!a.overlaps(&b, Point::new(8, 0)));
+        assert!(!a.overlaps(&b, Point::new(9, 1)));
+        assert!(b.overlaps(&a, Point::new(-9, 0)));
+        assert!(!b.overlaps(&a, Point::new(-10, 0)));
+        assert!(!a.overlaps(&b, Point::new(12, 0)));
+    }
+
+    #[test]
+    fn test_dilated() {
+        let dot = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let mask = CollisionMask::dilated(&dot, 1, [0; 3]).unwrap();
+        assert_eq!(mask.size(), Size::new(3, 3));
+        assert!(mask.contains(Point::new(0, 0)) && mask.contains(Point::new(2, 2)));
+        assert!(CollisionMask::dilated(&dot, 2, [0; 3]).is_none());
+        assert!(CollisionMask::dilated(&dot, u32::MAX / 2, [0; 3]).is_none());
+        assert!(CollisionMask::dilated(&dot, u32::MAX, [0; 3]).is_none());
+    }
+}
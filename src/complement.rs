@@ -0,0 +1,104 @@
+use crate::Image;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555,
+        Rgb565, Rgb666, Rgb888, RgbColor,
+    },
+    prelude::PixelColor,
+};
+
+/// Colors with an inverted counterpart.
+pub trait Complement: PixelColor {
+    /// Returns the color with every channel inverted
+    ///
+    /// This is an involution, so complementing twice returns the original
+    /// color.
+    fn complement(self) -> Self;
+}
+
+impl Complement for BinaryColor {
+    fn complement(self) -> Self {
+        self.invert()
+    }
+}
+
+macro_rules! impl_complement_rgb {
+    ($($color:ty),*) => {
+        $(
+            impl Complement for $color {
+                fn complement(self) -> Self {
+                    Self::new(
+                        Self::MAX_R - self.r(),
+                        Self::MAX_G - self.g(),
+                        Self::MAX_B - self.b(),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_complement_rgb!(
+    Rgb555, Rgb565, Rgb666, Rgb888, Bgr555, Bgr565, Bgr666, Bgr888
+);
+
+macro_rules! impl_complement_gray {
+    ($($color:ty),*) => {
+        $(
+            impl Complement for $color {
+                fn complement(self) -> Self {
+                    Self::new(Self::WHITE.luma() - self.luma())
+                }
+            }
+        )*
+    };
+}
+
+impl_complement_gray!(Gray2, Gray4, Gray8);
+
+impl<T, C> Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: Complement,
+{
+    /// Complement the image color and background color
+    ///
+    /// Combined with [`Image::with_inverted`], this gives a highlighted
+    /// variant of an item without picking extra colors.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+    /// use embedded_graphics_colorcast::Image;
+    /// # use embedded_graphics::mock_display::MockDisplay as Display;
+    ///
+    /// let mut display: Display<Rgb565> = Display::default();
+    /// let raw = ImageRaw::<BinaryColor>::new(&[0xf0], 8);
+    ///
+    /// Image::new(&raw, Point::zero(), Rgb565::YELLOW)
+    ///     .with_background(Rgb565::BLACK)
+    ///     .complemented()
+    ///     .draw(&mut display)?;
+    /// display.assert_pattern(&["BBBBWWWW"]);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub fn complemented(mut self) -> Self {
+        self.color = self.color.complement();
+        self.background = self.background.map(Complement::complement);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(Rgb565::new(31, 0, 10).complement(), Rgb565::new(0, 63, 21));
+        assert_eq!(Bgr888::WHITE.complement(), Bgr888::BLACK);
+        assert_eq!(Gray4::new(3).complement(), Gray4::new(12));
+        assert_eq!(BinaryColor::On.complement(), BinaryColor::Off);
+    }
+}
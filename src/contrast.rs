@@ -0,0 +1,62 @@
+use crate::view::{LumaWeights, ToLuma};
+
+/// Returns the difference of the BT.601 luma of two colors
+///
+/// The result ranges from 0 for colors of the same brightness to 255 for
+/// black and white.
+pub fn luma_contrast<C: ToLuma>(a: C, b: C) -> u8 {
+    let weights = LumaWeights::BT601;
+    a.to_luma(&weights).abs_diff(b.to_luma(&weights))
+}
+
+/// Pick a tint from `palette` that stands out against `background`
+///
+/// The palette is ordered by preference, the first color with a
+/// [`luma_contrast`] of at least `min_contrast` is returned. If none is
+/// contrasting enough, the one with the highest contrast is returned instead.
+/// Returns `None` only if the palette is empty.
+///
+/// This keeps icons readable on backgrounds chosen by the user.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, contrasting_tint};
+///
+/// let background = Rgb565::YELLOW;
+/// let palette = [Rgb565::WHITE, Rgb565::CSS_ORANGE, Rgb565::BLACK];
+/// let tint = contrasting_tint(background, &palette, 96).unwrap();
+/// assert_eq!(tint, Rgb565::BLACK);
+///
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+/// let icon = Image::new(&raw, Point::zero(), tint).with_background(background);
+/// ```
+pub fn contrasting_tint<C: ToLuma>(background: C, palette: &[C], min_contrast: u8) -> Option<C> {
+    palette
+        .iter()
+        .copied()
+        .find(|&color| luma_contrast(color, background) >= min_contrast)
+        .or_else(|| {
+            palette
+                .iter()
+                .copied()
+                .max_by_key(|&color| luma_contrast(color, background))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::{Gray8, GrayColor};
+
+    #[test]
+    fn test_contrasting_tint() {
+        let palette = [Gray8::new(100), Gray8::new(180), Gray8::new(20)];
+        assert_eq!(luma_contrast(Gray8::BLACK, Gray8::WHITE), 255);
+
+        let tint = contrasting_tint(Gray8::new(120), &palette, 50);
+        assert_eq!(tint, Some(Gray8::new(180)));
+        let fallback = contrasting_tint(Gray8::new(120), &palette, 200);
+        assert_eq!(fallback, Some(Gray8::new(20)));
+        assert_eq!(contrasting_tint(Gray8::BLACK, &[], 0), None);
+    }
+}
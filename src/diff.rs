@@ -0,0 +1,88 @@
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{PixelColor, Point, PointsIter},
+    primitives::Rectangle,
+};
+
+/// Returns the pixels that changed between two frames
+///
+/// Only pixels with a different color in `new` than in `old` are yielded,
+/// drawn in `on` or `off` according to their color in `new`. Pixels outside
+/// of one of the images are treated as `Off`, so frames of different sizes
+/// can be compared.
+///
+/// The pixels are relative to the top left corner of the frames. Use
+/// [`DrawTargetExt::translated`] to draw them at another position.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::diff;
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+///
+/// let old: ImageRaw<BinaryColor> = ImageRaw::new(&[0b1100_0000], 4);
+/// let new: ImageRaw<BinaryColor> = ImageRaw::new(&[0b0110_0000], 4);
+///
+/// display.draw_iter(diff(&old, &new, Rgb565::WHITE, Rgb565::BLACK))?;
+/// display.assert_pattern(&["K W"]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`DrawTargetExt::translated`]: embedded_graphics::draw_target::DrawTargetExt::translated
+pub fn diff<'a, A, B, C>(
+    old: &'a A,
+    new: &'a B,
+    on: C,
+    off: C,
+) -> impl Iterator<Item = Pixel<C>> + 'a
+where
+    A: OriginDimensions + GetPixel<Color = BinaryColor>,
+    B: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor + 'a,
+{
+    let size = old.size().component_max(new.size());
+
+    Rectangle::new(Point::zero(), size)
+        .points()
+        .filter_map(move |point| {
+            let before = old.pixel(point).unwrap_or(BinaryColor::Off);
+            let after = new.pixel(point).unwrap_or(BinaryColor::Off);
+            let color = if after.is_on() { on } else { off };
+            (before != after).then_some(Pixel(point, color))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_diff_different_sizes() {
+        let old = AsciiImage::from_rows(&["#.", "##"]).unwrap();
+        let new = AsciiImage::from_rows(&["###"]).unwrap();
+
+        let mut pixels = diff(&old, &new, BinaryColor::On, BinaryColor::Off);
+        assert_eq!(
+            pixels.next(),
+            Some(Pixel(Point::new(1, 0), BinaryColor::On))
+        );
+        assert_eq!(
+            pixels.next(),
+            Some(Pixel(Point::new(2, 0), BinaryColor::On))
+        );
+        assert_eq!(
+            pixels.next(),
+            Some(Pixel(Point::new(0, 1), BinaryColor::Off))
+        );
+        assert_eq!(
+            pixels.next(),
+            Some(Pixel(Point::new(1, 1), BinaryColor::Off))
+        );
+        assert_eq!(pixels.next(), None);
+    }
+}
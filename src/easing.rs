@@ -0,0 +1,111 @@
+//! Easing curves for transitions and tweens.
+//!
+//! Every curve maps a linear progress from [`Fixed::ZERO`] to [`Fixed::ONE`]
+//! to an eased progress in the same range, so motion speeds up and slows
+//! down instead of looking mechanically linear. Only integer math is used.
+//!
+//! ```rust
+//! use embedded_graphics_colorcast::{easing::Easing, fixed::Fixed};
+//!
+//! let half = Fixed::from_ratio(1, 2).unwrap();
+//! assert_eq!(Easing::InQuad.apply(half), Fixed::from_ratio(1, 4).unwrap());
+//! assert_eq!(Easing::InOutCubic.apply(half), half);
+//! ```
+
+use crate::fixed::Fixed;
+
+/// Easing curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Easing {
+    /// Constant speed
+    #[default]
+    Linear,
+    /// Start slowly and accelerate
+    InQuad,
+    /// Start quickly and decelerate
+    OutQuad,
+    /// Accelerate until halfway, then decelerate
+    InOutQuad,
+    /// Like [`Easing::InQuad`], but more pronounced
+    InCubic,
+    /// Like [`Easing::OutQuad`], but more pronounced
+    OutCubic,
+    /// Like [`Easing::InOutQuad`], but more pronounced
+    InOutCubic,
+}
+
+impl Easing {
+    /// Apply the curve to a progress between zero and one
+    ///
+    /// Progress outside of that range is clamped.
+    pub fn apply(self, t: Fixed) -> Fixed {
+        let t = t.clamp(Fixed::ZERO, Fixed::ONE);
+        let one = Fixed::ONE;
+        let half = Fixed::from_bits(Fixed::ONE.to_bits() / 2);
+
+        match self {
+            Easing::Linear => t,
+            Easing::InQuad => t * t,
+            Easing::OutQuad => one - (one - t) * (one - t),
+            Easing::InOutQuad if t < half => t * t * 2,
+            Easing::InOutQuad => {
+                let u = one - t;
+                one - u * u * 2
+            }
+            Easing::InCubic => t * t * t,
+            Easing::OutCubic => {
+                let u = one - t;
+                one - u * u * u
+            }
+            Easing::InOutCubic if t < half => t * t * t * 4,
+            Easing::InOutCubic => {
+                let u = one - t;
+                one - u * u * u * 4
+            }
+        }
+    }
+
+    /// Apply the curve to `step` out of `steps`
+    ///
+    /// This is convenient for animations driven by a frame counter. Returns
+    /// [`Fixed::ONE`] if `steps` is zero.
+    pub fn at(self, step: u32, steps: u32) -> Fixed {
+        let t = if steps == 0 {
+            Fixed::ONE
+        } else {
+            let step = step.min(steps);
+            Fixed::from_bits(((u64::from(step) << Fixed::FRAC_BITS) / u64::from(steps)) as i32)
+        };
+        self.apply(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::InQuad,
+            Easing::OutQuad,
+            Easing::InOutQuad,
+            Easing::InCubic,
+            Easing::OutCubic,
+            Easing::InOutCubic,
+        ] {
+            assert_eq!(easing.apply(Fixed::ZERO), Fixed::ZERO, "{easing:?}");
+            assert_eq!(easing.apply(Fixed::ONE), Fixed::ONE, "{easing:?}");
+            assert_eq!(easing.apply(Fixed::from_int(2)), Fixed::ONE, "{easing:?}");
+        }
+    }
+
+    #[test]
+    fn test_at() {
+        let quarter = Fixed::from_ratio(1, 4).unwrap();
+        assert_eq!(Easing::Linear.at(1, 4), quarter);
+        assert_eq!(Easing::OutQuad.at(1, 2), Fixed::from_ratio(3, 4).unwrap());
+        assert_eq!(Easing::InQuad.at(3, 0), Fixed::ONE);
+    }
+}
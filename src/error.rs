@@ -0,0 +1,153 @@
+use crate::{
+    anim::ani,
+    source::{StorageError, pack, rle},
+};
+use core::fmt;
+
+/// Errors of this crate, for propagating and logging failures uniformly.
+///
+/// The more specific errors of the fallible APIs convert into this type, so
+/// they can be returned with `?` from functions that return `Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// Sizes, positions or buffer lengths don't fit together
+    Geometry,
+    /// Image data couldn't be decoded
+    Decode,
+    /// Data doesn't match its checksum
+    Checksum,
+    /// The budget for a piece of work ran out before it was done
+    BudgetExhausted,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::Geometry => "geometry mismatch",
+            Error::Decode => "failed to decode image data",
+            Error::Checksum => "checksum mismatch",
+            Error::BudgetExhausted => "budget exhausted",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<rle::Error> for Error {
+    fn from(err: rle::Error) -> Self {
+        match err {
+            rle::Error::Checksum => Error::Checksum,
+            rle::Error::BadMagic | rle::Error::Truncated => Error::Decode,
+            rle::Error::TooLarge => Error::Geometry,
+        }
+    }
+}
+
+impl From<pack::Error> for Error {
+    fn from(err: pack::Error) -> Self {
+        match err {
+            pack::Error::Rle(err) => err.into(),
+            pack::Error::Checksum => Error::Checksum,
+            pack::Error::BadMagic | pack::Error::Truncated | pack::Error::BadFormat => {
+                Error::Decode
+            }
+            pack::Error::TooLarge => Error::Geometry,
+        }
+    }
+}
+
+impl From<ani::Error> for Error {
+    fn from(err: ani::Error) -> Self {
+        match err {
+            ani::Error::BadMagic | ani::Error::Truncated | ani::Error::BadFormat => Error::Decode,
+            ani::Error::TooLarge => Error::Geometry,
+        }
+    }
+}
+
+impl<E> From<crate::StrictError<E>> for Error
+where
+    E: Into<Error>,
+{
+    fn from(err: crate::StrictError<E>) -> Self {
+        match err {
+            crate::StrictError::OutOfBounds { .. } => Error::Geometry,
+            crate::StrictError::Target(err) => err.into(),
+        }
+    }
+}
+
+impl<E> From<StorageError<E>> for Error
+where
+    E: Into<Error>,
+{
+    fn from(err: StorageError<E>) -> Self {
+        match err {
+            StorageError::Storage(err) => err.into(),
+            StorageError::BadHeader | StorageError::Unsupported => Error::Decode,
+            StorageError::BufferTooSmall => Error::Geometry,
+        }
+    }
+}
+
+#[cfg(feature = "rp2040")]
+impl<E> From<crate::PipelineError<E>> for Error
+where
+    E: Into<Error>,
+{
+    fn from(err: crate::PipelineError<E>) -> Self {
+        match err {
+            crate::PipelineError::BufferTooSmall => Error::Geometry,
+            crate::PipelineError::Sink(err) => err.into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<crate::builder::Error> for Error {
+    fn from(err: crate::builder::Error) -> Self {
+        match err {
+            crate::builder::Error::Image(_) => Error::Decode,
+            crate::builder::Error::Rle(err) => err.into(),
+            crate::builder::Error::Pack(err) => err.into(),
+        }
+    }
+}
+
+impl From<core::convert::Infallible> for Error {
+    fn from(err: core::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_pack_error() {
+        assert_eq!(Error::from(pack::Error::Checksum), Error::Checksum);
+        assert_eq!(
+            Error::from(pack::Error::Rle(rle::Error::Truncated)),
+            Error::Decode
+        );
+    }
+
+    #[test]
+    fn test_from_ani_error() {
+        fn parse() -> Result<(), Error> {
+            Err(ani::Error::BadFormat)?
+        }
+        assert_eq!(parse(), Err(Error::Decode));
+        assert_eq!(Error::from(ani::Error::TooLarge), Error::Geometry);
+    }
+
+    #[test]
+    fn test_from_storage_error() {
+        let err: StorageError<core::convert::Infallible> = StorageError::BufferTooSmall;
+        assert_eq!(Error::from(err), Error::Geometry);
+        let err: StorageError<rle::Error> = StorageError::Storage(rle::Error::Checksum);
+        assert_eq!(Error::from(err), Error::Checksum);
+    }
+}
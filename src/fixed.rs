@@ -0,0 +1,348 @@
+//! Fixed-point math used by the transform views.
+//!
+//! [`Matrix`] describes the transformation of an [`Affine`](crate::view::Affine)
+//! view. Matrices can be precomputed and combined here, then applied with
+//! [`Affine::with_matrix`](crate::view::Affine::with_matrix).
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Signed Q16.16 fixed-point number.
+///
+/// Arithmetic wraps on overflow, like the integer operations in release
+/// builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// Number of fractional bits
+    pub const FRAC_BITS: u32 = 16;
+
+    /// The value `0`
+    pub const ZERO: Self = Self(0);
+
+    /// The value `1`
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+
+    /// Create a number from its raw Q16.16 representation
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw Q16.16 representation
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Convert a float, saturating at the limits of the fixed-point range
+    pub fn from_f32(value: f32) -> Self {
+        Self((value * (1 << Self::FRAC_BITS) as f32) as i32)
+    }
+
+    /// Convert to a float
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / (1 << Self::FRAC_BITS) as f32
+    }
+
+    /// Convert an integer
+    pub const fn from_int(value: i32) -> Self {
+        Self(value << Self::FRAC_BITS)
+    }
+
+    /// Convert the ratio `numerator / denominator`
+    ///
+    /// Returns `None` if the denominator is zero or the result doesn't fit.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let bits = ((numerator as i64) << Self::FRAC_BITS) / denominator as i64;
+        if bits < i32::MIN as i64 || bits > i32::MAX as i64 {
+            return None;
+        }
+        Some(Self(bits as i32))
+    }
+
+    /// Returns the largest integer less than or equal to the number
+    pub const fn floor(self) -> i32 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    /// Returns the smallest integer greater than or equal to the number
+    pub const fn ceil(self) -> i32 {
+        self.0.wrapping_add((1 << Self::FRAC_BITS) - 1) >> Self::FRAC_BITS
+    }
+
+    /// Returns the sine and cosine of an angle in whole degrees
+    pub const fn sin_cos(degrees: i32) -> (Self, Self) {
+        let degrees = degrees.rem_euclid(360);
+        let quarter = (degrees % 90) as usize;
+        let (sin, cos) = (SIN[quarter], SIN[90 - quarter]);
+        match degrees / 90 {
+            0 => (Self(sin), Self(cos)),
+            1 => (Self(cos), Self(-sin)),
+            2 => (Self(-sin), Self(-cos)),
+            _ => (Self(-cos), Self(sin)),
+        }
+    }
+
+    /// Round to the nearest integer, with halves rounded up
+    pub const fn round(self) -> i32 {
+        self.0.wrapping_add(1 << (Self::FRAC_BITS - 1)) >> Self::FRAC_BITS
+    }
+
+    /// Divide by `rhs`, returning `None` if `rhs` is zero
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+        Some(Self(
+            (((self.0 as i64) << Self::FRAC_BITS) / rhs.0 as i64) as i32,
+        ))
+    }
+
+    /// Returns the change of a rate per second over `dt` milliseconds
+    pub(crate) const fn over_millis(self, dt: u32) -> Self {
+        Self((self.0 as i64 * dt as i64 / 1000) as i32)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, see [`Fixed::checked_div`].
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(rhs).expect("division by zero")
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(((i64::from(self.0) * i64::from(rhs.0)) >> Self::FRAC_BITS) as i32)
+    }
+}
+
+impl Mul<i32> for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self {
+        Self(self.0.wrapping_mul(rhs))
+    }
+}
+
+/// Sine of 0 to 90 degrees in Q16.16
+#[rustfmt::skip]
+const SIN: [i32; 91] = [
+    0, 1144, 2287, 3430, 4572, 5712, 6850, 7987,
+    9121, 10252, 11380, 12505, 13626, 14742, 15855, 16962,
+    18064, 19161, 20252, 21336, 22415, 23486, 24550, 25607,
+    26656, 27697, 28729, 29753, 30767, 31772, 32768, 33754,
+    34729, 35693, 36647, 37590, 38521, 39441, 40348, 41243,
+    42126, 42995, 43852, 44695, 45525, 46341, 47143, 47930,
+    48703, 49461, 50203, 50931, 51643, 52339, 53020, 53684,
+    54332, 54963, 55578, 56175, 56756, 57319, 57865, 58393,
+    58903, 59396, 59870, 60326, 60764, 61183, 61584, 61966,
+    62328, 62672, 62997, 63303, 63589, 63856, 64104, 64332,
+    64540, 64729, 64898, 65048, 65177, 65287, 65376, 65446,
+    65496, 65526, 65536,
+];
+
+/// Matrix of an affine transformation.
+///
+/// A point `(x, y)` is mapped to `(a * x + b * y + tx, c * x + d * y + ty)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix {
+    /// Horizontal scale
+    pub a: Fixed,
+    /// Horizontal shear
+    pub b: Fixed,
+    /// Vertical shear
+    pub c: Fixed,
+    /// Vertical scale
+    pub d: Fixed,
+    /// Horizontal translation
+    pub tx: Fixed,
+    /// Vertical translation
+    pub ty: Fixed,
+}
+
+impl Matrix {
+    /// Transformation that doesn't change anything
+    pub const IDENTITY: Self = Self::new(Fixed::ONE, Fixed(0), Fixed(0), Fixed::ONE);
+
+    /// Create a linear transformation without translation
+    pub const fn new(a: Fixed, b: Fixed, c: Fixed, d: Fixed) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            d,
+            tx: Fixed(0),
+            ty: Fixed(0),
+        }
+    }
+
+    /// Scale by `sx` horizontally and `sy` vertically
+    pub const fn scale(sx: Fixed, sy: Fixed) -> Self {
+        Self::new(sx, Fixed(0), Fixed(0), sy)
+    }
+
+    /// Rotate clockwise around the origin, in screen coordinates
+    pub const fn rotation(degrees: i32) -> Self {
+        let (sin, cos) = Fixed::sin_cos(degrees);
+        Self::new(cos, Fixed(-sin.0), sin, cos)
+    }
+
+    /// Rotate clockwise around the origin by an angle in radians
+    ///
+    /// Unlike [`Matrix::rotation`] this isn't limited to whole degrees.
+    #[cfg(any(feature = "libm", feature = "micromath"))]
+    pub fn rotation_radians(radians: f32) -> Self {
+        let (sin, cos) = sin_cos_f32(radians);
+        let (sin, cos) = (Fixed::from_f32(sin), Fixed::from_f32(cos));
+        Self::new(cos, -sin, sin, cos)
+    }
+
+    /// Shear by `x` pixels per row and `y` pixels per column
+    pub const fn shear(x: Fixed, y: Fixed) -> Self {
+        Self::new(Fixed::ONE, x, y, Fixed::ONE)
+    }
+
+    /// Move by `(x, y)`
+    pub const fn translation(x: Fixed, y: Fixed) -> Self {
+        let mut matrix = Self::IDENTITY;
+        matrix.tx = x;
+        matrix.ty = y;
+        matrix
+    }
+
+    /// Returns the transformation that applies `self` first and then `next`
+    pub fn then(&self, next: &Self) -> Self {
+        Self {
+            a: next.a * self.a + next.b * self.c,
+            b: next.a * self.b + next.b * self.d,
+            c: next.c * self.a + next.d * self.c,
+            d: next.c * self.b + next.d * self.d,
+            tx: next.a * self.tx + next.b * self.ty + next.tx,
+            ty: next.c * self.tx + next.d * self.ty + next.ty,
+        }
+    }
+
+    /// Returns the inverse transformation, or `None` if it doesn't exist
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == Fixed(0) {
+            return None;
+        }
+
+        let a = self.d / det;
+        let b = -self.b / det;
+        let c = -self.c / det;
+        let d = self.a / det;
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            tx: -(a * self.tx + b * self.ty),
+            ty: -(c * self.tx + d * self.ty),
+        })
+    }
+
+    /// Apply the transformation to a point
+    pub fn apply(&self, x: Fixed, y: Fixed) -> (Fixed, Fixed) {
+        (
+            self.a * x + self.b * y + self.tx,
+            self.c * x + self.d * y + self.ty,
+        )
+    }
+}
+
+#[cfg(feature = "libm")]
+fn sin_cos_f32(x: f32) -> (f32, f32) {
+    libm::sincosf(x)
+}
+
+#[cfg(all(feature = "micromath", not(feature = "libm")))]
+fn sin_cos_f32(x: f32) -> (f32, f32) {
+    let x = micromath::F32(x);
+    (x.sin().0, x.cos().0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        let half = Fixed::from_ratio(1, 2).unwrap();
+        assert_eq!((half * 3).round(), 2);
+        assert_eq!((-half * 3).round(), -1);
+        assert_eq!((half * half * 4 + half).round(), 2);
+        assert_eq!(Fixed::from_ratio(1, 0), None);
+        assert_eq!(Fixed::from_ratio(i32::MAX, 1), None);
+        assert_eq!(Fixed::from_int(3) / half, Fixed::from_int(6));
+        assert_eq!(half.checked_div(Fixed::ZERO), None);
+        assert_eq!(
+            Fixed::from_bits(i32::MAX).round(),
+            i32::MIN >> Fixed::FRAC_BITS
+        );
+        assert_eq!(
+            Fixed::from_bits(i32::MAX).ceil(),
+            i32::MIN >> Fixed::FRAC_BITS
+        );
+    }
+
+    #[test]
+    fn test_matrix_inverse() {
+        let matrix = Matrix::rotation(30)
+            .then(&Matrix::scale(Fixed::from_int(2), Fixed::ONE))
+            .then(&Matrix::translation(
+                Fixed::from_int(5),
+                Fixed::from_int(-3),
+            ));
+        let (x, y) = matrix.apply(Fixed::from_int(4), Fixed::from_int(7));
+        let (x, y) = matrix.inverse().unwrap().apply(x, y);
+        assert_eq!((x.round(), y.round()), (4, 7));
+
+        assert_eq!(Fixed::sin_cos(270), (-Fixed::ONE, Fixed(0)));
+        assert_eq!(Matrix::scale(Fixed(0), Fixed::ONE).inverse(), None);
+    }
+
+    #[cfg(any(feature = "libm", feature = "micromath"))]
+    #[test]
+    fn test_rotation_radians() {
+        let exact = Matrix::rotation(30);
+        let float = Matrix::rotation_radians(core::f32::consts::PI / 6.0);
+        assert!((exact.a - float.a).to_f32().abs() < 0.01);
+        assert!((exact.c - float.c).to_f32().abs() < 0.01);
+    }
+}
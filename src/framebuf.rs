@@ -0,0 +1,156 @@
+use crate::Image;
+use embedded_graphics::{
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{PixelColor, Point},
+    primitives::Rectangle,
+};
+use embedded_graphics_framebuf::{FrameBuf, backends::FrameBufferBackend};
+
+impl<T, C> Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Draw the image directly into the backend of a [`FrameBuf`]
+    ///
+    /// The image is clipped to the frame buffer once and pixels are written
+    /// by index, skipping the per-pixel bounds checks of `draw_iter`. This
+    /// works with any backend, use [`Image::draw_framebuf_rows`] for the
+    /// array backends.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     pixelcolor::{BinaryColor, Rgb565},
+    ///     prelude::*,
+    /// };
+    /// use embedded_graphics_colorcast::Image;
+    /// use embedded_graphics_framebuf::FrameBuf;
+    ///
+    /// let mut data = [Rgb565::BLACK; 16 * 8];
+    /// let mut fb = FrameBuf::new(&mut data, 16, 8);
+    ///
+    /// let raw = ImageRaw::<BinaryColor>::new(&[0xff, 0x81, 0xff], 8);
+    /// Image::new(&raw, Point::new(4, 2), Rgb565::RED).draw_framebuf(&mut fb);
+    /// assert_eq!(fb.get_color_at(Point::new(5, 3)), Rgb565::BLACK);
+    /// assert_eq!(fb.get_color_at(Point::new(4, 3)), Rgb565::RED);
+    /// ```
+    pub fn draw_framebuf<B>(&self, fb: &mut FrameBuf<C, B>)
+    where
+        B: FrameBufferBackend<Color = C>,
+    {
+        for (index, color) in self.indexed_pixels(fb.size()) {
+            fb.data.set(index, color);
+        }
+    }
+
+    /// Draw the image into a [`FrameBuf`] backed by an array, row by row
+    ///
+    /// Every visible row is split into runs of equal pixels and each run is
+    /// written with a single `fill`, instead of one
+    /// [`FrameBufferBackend::set`] call per pixel.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{
+    ///     image::ImageRaw,
+    ///     pixelcolor::{BinaryColor, Rgb565},
+    ///     prelude::*,
+    /// };
+    /// use embedded_graphics_colorcast::Image;
+    /// use embedded_graphics_framebuf::FrameBuf;
+    ///
+    /// let mut data = [Rgb565::BLACK; 16 * 8];
+    /// let mut fb = FrameBuf::new(&mut data, 16, 8);
+    ///
+    /// let raw = ImageRaw::<BinaryColor>::new(&[0xff, 0x81, 0xff], 8);
+    /// Image::new(&raw, Point::new(4, 2), Rgb565::RED).draw_framebuf_rows(&mut fb);
+    /// assert_eq!(fb.get_color_at(Point::new(5, 3)), Rgb565::BLACK);
+    /// assert_eq!(fb.get_color_at(Point::new(4, 3)), Rgb565::RED);
+    /// ```
+    pub fn draw_framebuf_rows<B>(&self, fb: &mut FrameBuf<C, B>)
+    where
+        B: FrameBufferBackend<Color = C> + AsMut<[C]>,
+    {
+        let size = fb.size();
+        let area = self
+            .bounding_box()
+            .intersection(&Rectangle::new(Point::zero(), size));
+        let (left, width) = (area.top_left.x, area.size.width as usize);
+        let data = fb.data.as_mut();
+
+        for y in area.rows() {
+            let start = y as usize * size.width as usize + left as usize;
+            let row = &mut data[start..start + width];
+            let is_on = |x: usize| {
+                let point = Point::new(left + x as i32, y) - self.position;
+                self.source_pixel(point) == Some(BinaryColor::On)
+            };
+
+            let mut x = 0;
+            while x < width {
+                let on = is_on(x);
+                let end = (x + 1..width).find(|&x| is_on(x) != on).unwrap_or(width);
+                if let Some(color) = if on {
+                    Some(self.color)
+                } else {
+                    self.background
+                } {
+                    row[x..end].fill(color);
+                }
+                x = end;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        image::ImageRaw,
+        pixelcolor::Rgb565,
+        prelude::{Point, RgbColor},
+    };
+
+    #[test]
+    fn test_draw_framebuf_clipped() {
+        let mut data = [Rgb565::BLACK; 4 * 2];
+        let mut fb = FrameBuf::new(&mut data, 4, 2);
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0101_0000], 4);
+        Image::new(&raw, Point::new(-1, 1), Rgb565::RED)
+            .with_background(Rgb565::BLUE)
+            .draw_framebuf(&mut fb);
+
+        let expected = [Rgb565::BLACK; 4].into_iter().chain([
+            Rgb565::BLUE,
+            Rgb565::RED,
+            Rgb565::BLUE,
+            Rgb565::BLACK,
+        ]);
+        assert!(data.into_iter().eq(expected));
+    }
+
+    #[test]
+    fn test_draw_framebuf_rows_matches_set() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1100_1000, 0b0011_1110], 7);
+        for background in [None, Some(Rgb565::BLUE)] {
+            let mut image = Image::new(&raw, Point::new(-2, 1), Rgb565::RED);
+            if let Some(background) = background {
+                image = image.with_background(background);
+            }
+
+            let mut expected = [Rgb565::BLACK; 6 * 3];
+            image.draw_framebuf(&mut FrameBuf::new(&mut expected, 6, 3));
+            let mut data = [Rgb565::BLACK; 6 * 3];
+            image.draw_framebuf_rows(&mut FrameBuf::new(&mut data, 6, 3));
+            assert_eq!(data, expected);
+
+            let mut owned = FrameBuf::new([Rgb565::BLACK; 6 * 3], 6, 3);
+            image.draw_framebuf_rows(&mut owned);
+            assert_eq!(owned.data, expected);
+        }
+    }
+}
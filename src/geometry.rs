@@ -0,0 +1,38 @@
+use embedded_graphics::primitives::Rectangle;
+
+/// Returns the smallest rectangle containing both rectangles
+///
+/// Zero sized rectangles are ignored.
+pub(crate) fn union(a: &Rectangle, b: &Rectangle) -> Rectangle {
+    match (a.bottom_right(), b.bottom_right()) {
+        (Some(a_br), Some(b_br)) => Rectangle::with_corners(
+            a.top_left.component_min(b.top_left),
+            a_br.component_max(b_br),
+        ),
+        (Some(_), None) => *a,
+        (None, _) => *b,
+    }
+}
+
+/// Returns `true` if two areas share at least one pixel
+pub(crate) fn overlaps(a: &Rectangle, b: &Rectangle) -> bool {
+    !a.intersection(b).is_zero_sized()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::prelude::{Point, Size};
+
+    #[test]
+    fn test_union() {
+        let a = Rectangle::new(Point::new(0, 0), Size::new(2, 2));
+        let b = Rectangle::new(Point::new(3, -1), Size::new(1, 1));
+        assert_eq!(
+            union(&a, &b),
+            Rectangle::new(Point::new(0, -1), Size::new(4, 3))
+        );
+        assert_eq!(union(&a, &Rectangle::zero()), a);
+        assert_eq!(union(&Rectangle::zero(), &b), b);
+    }
+}
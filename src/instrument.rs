@@ -0,0 +1,142 @@
+use crate::{DrawStats, stats::Counted};
+use embedded_graphics::{
+    Pixel,
+    prelude::{Dimensions, DrawTarget, Point},
+    primitives::Rectangle,
+};
+
+/// Statistics reported by [`Instrumented`] after every draw call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DrawEvent {
+    /// Pixels and runs sent to the target, including clipped pixels
+    pub stats: DrawStats,
+    /// Number of pixels that were outside the target
+    pub clipped: u32,
+    /// Bounding box of the pixels that were inside the target
+    pub area: Rectangle,
+}
+
+/// Draw target adapter that reports statistics about every draw call.
+///
+/// This allows tracking performance regressions of UI code on the device
+/// itself, without an external profiler. Every call to `draw_iter` is
+/// reported separately. Fills are forwarded pixel by pixel, so the
+/// instrumented target can be slower than the target itself.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{DrawEvent, Image, Instrumented};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0b1101_0000], 4);
+///
+/// let mut events = 0;
+/// let mut target = Instrumented::new(&mut display, |event: &DrawEvent| {
+///     assert_eq!(event.stats.runs, 2);
+///     events += 1;
+/// });
+/// Image::new(&raw, Point::zero(), Rgb565::WHITE).draw(&mut target)?;
+/// drop(target);
+/// assert_eq!(events, 1);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct Instrumented<'a, D, F> {
+    target: &'a mut D,
+    callback: F,
+}
+
+impl<'a, D, F> Instrumented<'a, D, F>
+where
+    D: DrawTarget,
+    F: FnMut(&DrawEvent),
+{
+    /// Report every draw call to `target` to the callback
+    pub const fn new(target: &'a mut D, callback: F) -> Self {
+        Self { target, callback }
+    }
+
+    /// Returns the callback
+    pub fn into_inner(self) -> F {
+        self.callback
+    }
+}
+
+impl<D, F> Dimensions for Instrumented<'_, D, F>
+where
+    D: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D, F> DrawTarget for Instrumented<'_, D, F>
+where
+    D: DrawTarget,
+    F: FnMut(&DrawEvent),
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounds = self.target.bounding_box();
+        let mut stats = DrawStats::default();
+        let mut clipped = 0;
+        let mut corners: Option<(Point, Point)> = None;
+
+        let pixels = Counted::new(pixels.into_iter(), &mut stats).inspect(|&Pixel(point, _)| {
+            if !bounds.contains(point) {
+                clipped += 1;
+                return;
+            }
+            corners = Some(match corners {
+                Some((min, max)) => (min.component_min(point), max.component_max(point)),
+                None => (point, point),
+            });
+        });
+        let result = self.target.draw_iter(pixels);
+
+        let area = corners.map_or(Rectangle::zero(), |(min, max)| {
+            Rectangle::with_corners(min, max)
+        });
+        (self.callback)(&DrawEvent {
+            stats,
+            clipped,
+            area,
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Size};
+
+    #[test]
+    fn test_instrumented_clipping() {
+        let mut display = MockDisplay::new();
+        display.set_allow_out_of_bounds_drawing(true);
+        let mut event = DrawEvent::default();
+
+        let mut target = Instrumented::new(&mut display, |e: &DrawEvent| event = *e);
+        let pixels = [Point::new(-1, 0), Point::new(0, 0), Point::new(2, 1)];
+        target
+            .draw_iter(pixels.map(|point| Pixel(point, BinaryColor::On)))
+            .unwrap();
+
+        assert_eq!(
+            event,
+            DrawEvent {
+                stats: DrawStats { pixels: 3, runs: 2 },
+                clipped: 1,
+                area: Rectangle::new(Point::zero(), Size::new(3, 2)),
+            }
+        );
+    }
+}
@@ -38,6 +38,12 @@
 //! ```
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(all(test, feature = "macros"))]
+extern crate self as embedded_graphics_colorcast;
+
 use embedded_graphics::{
     Drawable, Pixel,
     geometry::OriginDimensions,
@@ -47,6 +53,97 @@ use embedded_graphics::{
     primitives::Rectangle,
 };
 
+pub mod anim;
+mod any;
+mod blend;
+mod bordered;
+#[cfg(feature = "std")]
+pub mod builder;
+#[cfg(feature = "canvas")]
+mod canvas;
+mod capture;
+mod chunked;
+mod collision;
+mod complement;
+mod contrast;
+mod diff;
+pub mod easing;
+mod error;
+pub mod fixed;
+#[cfg(feature = "framebuf")]
+mod framebuf;
+mod geometry;
+#[cfg(feature = "instrument")]
+mod instrument;
+mod masked;
+#[cfg(feature = "otsu")]
+mod otsu;
+mod palette;
+mod particles;
+mod pattern;
+#[cfg(feature = "rp2040")]
+mod pipeline;
+mod pixels;
+mod runs;
+mod save_under;
+pub mod scanline;
+mod scene;
+mod scheduler;
+pub mod source;
+mod sparse;
+mod sprite;
+mod sprite_pool;
+mod stats;
+mod stencil;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod theme;
+mod tinted;
+pub mod transition;
+pub mod view;
+pub mod widget;
+
+pub use self::any::AnyTintedImage;
+pub use self::blend::BlendMode;
+pub use self::bordered::Bordered;
+pub use self::capture::CaptureTarget;
+pub use self::chunked::ChunkedDraw;
+pub use self::collision::CollisionMask;
+pub use self::complement::Complement;
+pub use self::contrast::{contrasting_tint, luma_contrast};
+pub use self::diff::diff;
+pub use self::error::Error;
+#[cfg(feature = "instrument")]
+pub use self::instrument::{DrawEvent, Instrumented};
+pub use self::masked::MaskedImage;
+#[cfg(feature = "otsu")]
+pub use self::otsu::otsu_threshold;
+pub use self::palette::Paletted;
+pub use self::particles::Particles;
+pub use self::pattern::PatternFill;
+#[cfg(feature = "rp2040")]
+pub use self::pipeline::{PipelineError, RowSink};
+pub use self::pixels::{ExactPixels, Pixels};
+pub use self::runs::{RowRuns, Rows, Runs};
+pub use self::save_under::SaveUnder;
+pub use self::scene::Scene;
+pub use self::scheduler::FrameScheduler;
+pub use self::sprite::Sprite;
+pub use self::sprite_pool::{SpriteHandle, SpritePool};
+pub use self::stats::DrawStats;
+pub use self::stencil::Stenciled;
+pub use self::theme::{Role, Theme, ThemeSet, ThemedImage};
+pub use self::tinted::Tinted;
+
+#[cfg(feature = "macros")]
+pub use embedded_graphics_colorcast_macros::include_binary_image;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::source::ascii::{pack as ascii_pack, width as ascii_width};
+    pub use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor};
+}
+
 /// Image object.
 ///
 /// The `Image` struct is a wrapper around an [`ImageRaw<BinaryColor>`] and can be rendered
@@ -54,7 +151,7 @@ use embedded_graphics::{
 ///
 /// This takes ownership of the `ImageRaw` since it's only holding a reference,
 /// not the entire image data.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug)]
 pub struct Image<'a, T, C>
 where
     T: OriginDimensions + GetPixel<Color = BinaryColor>,
@@ -64,6 +161,7 @@ where
     position: Point,
     color: C,
     background: Option<C>,
+    inverted: bool,
 }
 
 impl<'a, T, C> Image<'a, T, C>
@@ -78,6 +176,7 @@ where
             position,
             color,
             background: None,
+            inverted: false,
         }
     }
 
@@ -92,6 +191,194 @@ where
         self.background = Some(color);
         self
     }
+
+    /// Swap the `On` and `Off` pixels of the source
+    ///
+    /// This highlights a selected item without a second, inverted asset.
+    /// Without a background, the former `Off` pixels are drawn in the image
+    /// color and the former `On` pixels become transparent.
+    pub fn with_inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Returns `true` if the source pixels are swapped
+    pub const fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Draw the image, but fail if it doesn't fit into the target
+    ///
+    /// Normal drawing silently clips pixels outside of the target. This
+    /// returns [`StrictError::OutOfBounds`] without drawing anything instead,
+    /// which helps to catch layout bugs during development.
+    pub fn draw_strict<D>(&self, target: &mut D) -> Result<(), StrictError<D::Error>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let image = self.bounding_box();
+        let bounds = target.bounding_box();
+        if bounds.intersection(&image) != image {
+            return Err(StrictError::OutOfBounds {
+                image,
+                target: bounds,
+            });
+        }
+
+        self.draw(target).map_err(StrictError::Target)
+    }
+
+    /// Draw the image and report how many pixels and runs were sent to the target
+    pub fn draw_counted<D>(&self, target: &mut D) -> Result<DrawStats, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut stats = DrawStats::default();
+        let pixels = Pixels::clipped(*self, &target.bounding_box());
+        target.draw_iter(stats::Counted::new(pixels, &mut stats))?;
+        Ok(stats)
+    }
+
+    /// Draw the image and return the area of the target that was affected
+    ///
+    /// The returned rectangle is the part of the image's bounding box that
+    /// lies inside the target, which is the window an e-paper display needs to
+    /// refresh. It's zero sized if the image is completely outside the target.
+    pub fn draw_dirty<D>(&self, target: &mut D) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.draw(target)?;
+        Ok(self.bounding_box().intersection(&target.bounding_box()))
+    }
+
+    /// Draw the image at every position in a single batch of pixels
+    ///
+    /// The positions replace the position of the image, which makes this a
+    /// cheap way to draw starfields or repeated decorations.
+    pub fn stamp<D, I>(&self, target: &mut D, positions: I) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        I: IntoIterator<Item = Point>,
+    {
+        target.draw_iter(positions.into_iter().flat_map(|position| {
+            let offset = position - self.position;
+            self.pixels()
+                .map(move |Pixel(point, color)| Pixel(point + offset, color))
+        }))
+    }
+
+    /// Cover an area with copies of the image
+    ///
+    /// The tiles are aligned to the position of the image and clipped at the
+    /// edges of `area`.
+    pub fn tile_fill<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let texture = scanline::Texture::new(self.image, self.position);
+        target.draw_iter(scanline::fill_region(area, texture, |_, color| match self
+            .apply_inversion(color)
+        {
+            BinaryColor::On => Some(self.color),
+            BinaryColor::Off => self.background,
+        }))
+    }
+
+    /// Flip a source color if the image is inverted
+    fn apply_inversion(&self, color: BinaryColor) -> BinaryColor {
+        if self.inverted { color.invert() } else { color }
+    }
+
+    /// Returns the source pixel at `point`, with the inversion applied
+    fn source_pixel(&self, point: Point) -> Option<BinaryColor> {
+        self.image
+            .pixel(point)
+            .map(|color| self.apply_inversion(color))
+    }
+
+    /// Pixels clipped to a row-major buffer of the given size, with their index
+    #[cfg(any(feature = "framebuf", feature = "canvas"))]
+    fn indexed_pixels(
+        &self,
+        size: embedded_graphics::prelude::Size,
+    ) -> impl Iterator<Item = (usize, C)> + '_ {
+        let area = self
+            .bounding_box()
+            .intersection(&Rectangle::new(Point::zero(), size));
+
+        area.points().filter_map(move |p| {
+            let point = p - self.position;
+            let color = if self.source_pixel(point) == Some(BinaryColor::On) {
+                self.color
+            } else {
+                self.background?
+            };
+            Some((p.y as usize * size.width as usize + p.x as usize, color))
+        })
+    }
+
+    /// Draw the image in bounded chunks, see [`ChunkedDraw`]
+    pub const fn draw_chunked(&self) -> ChunkedDraw<'_, 'a, T, C> {
+        ChunkedDraw::new(self)
+    }
+
+    /// Returns the color of a source pixel, or `None` if it isn't drawn
+    fn pixel_at(&self, point: Point) -> Option<C> {
+        if self.source_pixel(point) == Some(BinaryColor::On) {
+            Some(self.color)
+        } else {
+            self.background
+        }
+    }
+
+    /// Returns `true` if every pixel of the image is drawn
+    fn is_opaque(&self) -> bool {
+        self.background.is_some()
+    }
+
+    /// Returns an iterator over the pixels that are drawn
+    pub fn pixels(&self) -> Pixels<'a, T, C> {
+        Pixels::new(*self)
+    }
+
+    /// Returns an exact size iterator over the pixels, if every pixel is drawn
+    ///
+    /// This is `None` for images without a background.
+    pub fn exact_pixels(&self) -> Option<ExactPixels<'a, T, C>> {
+        ExactPixels::new(*self)
+    }
+}
+
+impl<T, C> Clone for Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, C> Copy for Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+}
+
+/// Error returned by [`Image::draw_strict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StrictError<E> {
+    /// Parts of the image are outside the target
+    OutOfBounds {
+        /// Bounding box of the image
+        image: Rectangle,
+        /// Bounding box of the target
+        target: Rectangle,
+    },
+    /// Drawing to the target failed
+    Target(E),
 }
 
 impl<T, C> Drawable for Image<'_, T, C>
@@ -106,17 +393,28 @@ where
     where
         D: DrawTarget<Color = C>,
     {
-        target.draw_iter(self.image.bounding_box().points().flat_map(|point| {
-            let color = if self.image.pixel(point) == Some(BinaryColor::On) {
-                self.color
-            } else if let Some(background) = self.background {
-                background
-            } else {
-                return None;
-            };
+        let bounds = target.bounding_box();
+        let area = self.bounding_box();
 
-            Some(Pixel(self.position + point, color))
-        }))
+        // Opaque images that are fully visible set every pixel of their
+        // area, which targets can write as contiguous rows.
+        if let Some(background) = self.background
+            && bounds.intersection(&area) == area
+        {
+            let colors =
+                self.image
+                    .bounding_box()
+                    .points()
+                    .map(|point| match self.source_pixel(point) {
+                        Some(BinaryColor::On) => self.color,
+                        _ => background,
+                    });
+            return target.fill_contiguous(&area, colors);
+        }
+
+        // Only visit the visible part, instead of letting the target drop
+        // the pixels outside of it.
+        target.draw_iter(Pixels::clipped(*self, &bounds))
     }
 }
 
@@ -157,6 +455,7 @@ where
             position: self.position + by,
             color: self.color,
             background: self.background,
+            inverted: self.inverted,
         }
     }
 
@@ -218,4 +517,125 @@ mod tests {
         let image_raw = ImageRaw::<BinaryColor>::new(&[0b10101010, 0b01010101], 8);
         Image::new(&image_raw, Point::zero(), BinaryColor::Off);
     }
+
+    #[test]
+    fn test_image_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Image<'static, ImageRaw<'static, BinaryColor>, Rgb666>>();
+    }
+
+    #[test]
+    fn test_draw_strict() {
+        use embedded_graphics::{mock_display::MockDisplay, prelude::Size};
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b10101010, 0b01010101], 8);
+        let mut display = MockDisplay::<Rgb666>::new();
+
+        let inside = Image::new(&image_raw, Point::new(56, 62), Rgb666::WHITE);
+        assert_eq!(inside.draw_strict(&mut display), Ok(()));
+
+        let outside = Image::new(&image_raw, Point::new(-1, 0), Rgb666::WHITE);
+        assert_eq!(
+            outside.draw_strict(&mut display),
+            Err(StrictError::OutOfBounds {
+                image: Rectangle::new(Point::new(-1, 0), Size::new(8, 2)),
+                target: display.bounding_box(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_draw_counted() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b11011000, 0b00000011], 8);
+        let mut display = MockDisplay::<Rgb666>::new();
+
+        let stats = Image::new(&image_raw, Point::zero(), Rgb666::WHITE)
+            .draw_counted(&mut display)
+            .unwrap();
+        assert_eq!(stats, DrawStats { pixels: 6, runs: 3 });
+    }
+
+    #[test]
+    fn test_draw_dirty() {
+        use embedded_graphics::{mock_display::MockDisplay, prelude::Size};
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b10101010, 0b01010101], 8);
+        let mut display = MockDisplay::<Rgb666>::new();
+        display.set_allow_out_of_bounds_drawing(true);
+
+        let dirty = Image::new(&image_raw, Point::new(60, -1), Rgb666::WHITE)
+            .draw_dirty(&mut display)
+            .unwrap();
+        assert_eq!(dirty, Rectangle::new(Point::new(60, 0), Size::new(4, 1)));
+    }
+
+    #[test]
+    fn test_stamp() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut display = MockDisplay::new();
+
+        Image::new(&image_raw, Point::new(10, 10), BinaryColor::On)
+            .stamp(&mut display, [Point::new(0, 0), Point::new(2, 1)])
+            .unwrap();
+        display.assert_pattern(&["#   ", " ## ", "   #"]);
+    }
+
+    #[test]
+    fn test_tile_fill() {
+        use embedded_graphics::{mock_display::MockDisplay, prelude::Size};
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut display = MockDisplay::new();
+
+        Image::new(&image_raw, Point::new(1, 0), BinaryColor::On)
+            .with_background(BinaryColor::Off)
+            .tile_fill(
+                &mut display,
+                &Rectangle::new(Point::zero(), Size::new(5, 3)),
+            )
+            .unwrap();
+        display.assert_pattern(&[".#.#.", "#.#.#", ".#.#."]);
+    }
+
+    #[test]
+    fn test_draw_fully_visible() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0100_0000], 3);
+        let mut display = MockDisplay::new();
+
+        Image::new(&image_raw, Point::new(1, 0), BinaryColor::On)
+            .with_background(BinaryColor::Off)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&[" #.#", " .#."]);
+    }
+
+    #[test]
+    fn test_with_inverted() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let image_raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 4);
+        let mut display = MockDisplay::new();
+
+        Image::new(&image_raw, Point::zero(), BinaryColor::On)
+            .with_inverted(true)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&[" # #"]);
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_include_binary_image() {
+        let image = include_binary_image!("testdata/checker.png", threshold = 128);
+        assert_eq!(image.size(), embedded_graphics::prelude::Size::new(4, 2));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+    }
 }
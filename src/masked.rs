@@ -0,0 +1,202 @@
+use crate::{AnyTintedImage, Image};
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, PointsIter, Transform},
+    primitives::Rectangle,
+};
+
+/// Image drawn only where a separate mask is `On`.
+///
+/// The mask is aligned with the top left corner of the image and also
+/// applies to the background. Keeping shape and content in separate planes
+/// allows a single mask, like a [`ShapeMask`](crate::view::ShapeMask), to
+/// crop many images.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::Image;
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 4);
+/// let mask = ImageRaw::<BinaryColor>::new(&[0b0110_0000], 4);
+///
+/// Image::new(&raw, Point::zero(), BinaryColor::On)
+///     .with_background(BinaryColor::Off)
+///     .with_mask(&mask)
+///     .draw(&mut display)?;
+/// display.assert_pattern(&[" .# "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct MaskedImage<'a, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    mask: &'a M,
+}
+
+impl<'a, T, M, C> MaskedImage<'a, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Mask an image
+    pub const fn new(image: Image<'a, T, C>, mask: &'a M) -> Self {
+        Self { image, mask }
+    }
+
+    /// Returns the image without the mask
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the mask
+    pub const fn mask(&self) -> &'a M {
+        self.mask
+    }
+
+    /// Returns the color of a source pixel, or `None` if it isn't drawn
+    fn pixel_at(&self, point: Point) -> Option<C> {
+        if self.mask.pixel(point) != Some(BinaryColor::On) {
+            return None;
+        }
+        self.image.pixel_at(point)
+    }
+}
+
+impl<'a, T, C> Image<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Only draw the pixels where `mask` is `On`, see [`MaskedImage`]
+    pub const fn with_mask<M>(self, mask: &'a M) -> MaskedImage<'a, T, M, C>
+    where
+        M: GetPixel<Color = BinaryColor>,
+    {
+        MaskedImage::new(self, mask)
+    }
+}
+
+impl<T, M, C> Clone for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, M, C> Copy for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+}
+
+impl<T, M, C> Drawable for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let position = self.image.position;
+        let area = self.bounding_box().intersection(&target.bounding_box());
+        target.draw_iter(
+            area.points()
+                .filter_map(|point| Some(Pixel(point, self.pixel_at(point - position)?))),
+        )
+    }
+}
+
+impl<T, M, C> Transform for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            image: self.image.translate(by),
+            mask: self.mask,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.image.translate_mut(by);
+        self
+    }
+}
+
+impl<T, M, C> Dimensions for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+impl<T, M, C> AnyTintedImage<C> for MaskedImage<'_, T, M, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    M: GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn area(&self) -> Rectangle {
+        self.bounding_box()
+    }
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        let point = point - self.image.position;
+        self.image
+            .image
+            .bounding_box()
+            .contains(point)
+            .then(|| self.pixel_at(point))?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    #[test]
+    fn test_masked_any_matches_draw() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 4);
+        let mask = ImageRaw::<BinaryColor>::new(&[0b0110_0000], 4);
+        let image = Image::new(&raw, Point::new(1, 0), BinaryColor::On)
+            .with_background(BinaryColor::Off)
+            .with_mask(&mask);
+
+        let mut display = MockDisplay::new();
+        image.draw(&mut display).unwrap();
+        display.assert_pattern(&["  .# "]);
+
+        let any: &dyn AnyTintedImage<BinaryColor> = &image;
+        assert_eq!(any.color_at(Point::new(1, 0)), None);
+        assert_eq!(any.color_at(Point::new(3, 0)), Some(BinaryColor::On));
+        assert!(!any.is_opaque());
+    }
+}
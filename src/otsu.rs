@@ -0,0 +1,86 @@
+use crate::view::{LumaWeights, ToLuma};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    prelude::{Dimensions, PointsIter},
+};
+
+/// Compute a binary threshold for a source with Otsu's method
+///
+/// The threshold maximizes the variance between the dark and the light pixels
+/// of the image, based on a histogram of the BT.601 luma. It can be passed to
+/// [`Thresholded::new`](crate::view::Thresholded::new) to convert images
+/// without hard-coding a threshold per image.
+///
+/// If every pixel has the same luma, there is nothing to split and that luma
+/// is returned, so all pixels turn `On`. An empty source returns `0`.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::Gray8, prelude::*};
+/// use embedded_graphics_colorcast::{otsu_threshold, view::Thresholded};
+///
+/// let photo: ImageRaw<Gray8> = ImageRaw::new(&[10, 20, 200, 220], 2);
+/// let threshold = otsu_threshold(&photo);
+/// let binary = Thresholded::new(&photo, threshold);
+/// ```
+pub fn otsu_threshold<T>(source: &T) -> u8
+where
+    T: OriginDimensions + GetPixel,
+    T::Color: ToLuma,
+{
+    let mut histogram = [0u32; 256];
+    for point in source.bounding_box().points() {
+        if let Some(color) = source.pixel(point) {
+            histogram[usize::from(color.to_luma(&LumaWeights::BT601))] += 1;
+        }
+    }
+
+    let total: u64 = histogram.iter().map(|&n| u64::from(n)).sum();
+    let sum: u64 = (0..).zip(histogram).map(|(i, n)| i * u64::from(n)).sum();
+
+    let (mut weight, mut partial) = (0u64, 0u64);
+    let (mut best, mut threshold) = (0f32, None);
+    for (level, &n) in (0u8..=255).zip(&histogram) {
+        weight += u64::from(n);
+        partial += u64::from(level) * u64::from(n);
+        if weight == 0 || weight == total {
+            continue;
+        }
+
+        let dark = partial as f32 / weight as f32;
+        let light = (sum - partial) as f32 / (total - weight) as f32;
+        let variance = weight as f32 * (total - weight) as f32 * (dark - light) * (dark - light);
+        if variance > best {
+            best = variance;
+            threshold = Some(level);
+        }
+    }
+
+    // Pixels up to the computed level are dark, `Thresholded` turns pixels at
+    // or above its threshold `On`.
+    match threshold {
+        Some(level) => level.saturating_add(1),
+        None => (0u8..=255)
+            .zip(histogram)
+            .find(|&(_, n)| n > 0)
+            .map_or(0, |(level, _)| level),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, pixelcolor::Gray8};
+
+    #[test]
+    fn test_otsu_threshold() {
+        let image: ImageRaw<Gray8> = ImageRaw::new(&[10, 20, 30, 200, 210, 220], 3);
+        assert_eq!(otsu_threshold(&image), 31);
+
+        let flat: ImageRaw<Gray8> = ImageRaw::new(&[50, 50], 2);
+        assert_eq!(otsu_threshold(&flat), 50);
+
+        let empty: ImageRaw<Gray8> = ImageRaw::new(&[], 1);
+        assert_eq!(otsu_threshold(&empty), 0);
+    }
+}
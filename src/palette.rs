@@ -0,0 +1,95 @@
+use crate::view::{Posterized, ToLuma};
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, PointsIter, Transform},
+    primitives::Rectangle,
+};
+
+/// Posterized image drawn with a palette.
+///
+/// Every level of the source is drawn with the palette entry of the same
+/// index, levels without an entry are skipped.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{Gray2, Gray8}, prelude::*};
+/// use embedded_graphics_colorcast::{Paletted, view::Posterized};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Gray2> = Display::default();
+///
+/// let photo: ImageRaw<Gray8> = ImageRaw::new(&[0, 90, 170, 255], 4);
+/// let posterized = Posterized::new(&photo, 4).unwrap();
+/// let palette = [Gray2::new(0), Gray2::new(1), Gray2::new(2), Gray2::new(3)];
+///
+/// Paletted::new(&posterized, Point::zero(), &palette).draw(&mut display)?;
+/// display.assert_pattern(&["0123"]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Paletted<'a, T, C> {
+    source: &'a Posterized<'a, T>,
+    position: Point,
+    palette: &'a [C],
+}
+
+impl<'a, T, S, C> Paletted<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = S>,
+    S: ToLuma,
+    C: PixelColor,
+{
+    /// Create a new image at a given position
+    pub const fn new(source: &'a Posterized<'a, T>, position: Point, palette: &'a [C]) -> Self {
+        Self {
+            source,
+            position,
+            palette,
+        }
+    }
+}
+
+impl<T, S, C> Drawable for Paletted<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = S>,
+    S: ToLuma,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(self.source.bounding_box().points().filter_map(|point| {
+            let level = self.source.level(point)?;
+            let color = *self.palette.get(usize::from(level))?;
+            Some(Pixel(self.position + point, color))
+        }))
+    }
+}
+
+impl<T, C> Dimensions for Paletted<'_, T, C>
+where
+    T: OriginDimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.source.bounding_box().translate(self.position)
+    }
+}
+
+impl<T, C> Transform for Paletted<'_, T, C> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+        self
+    }
+}
@@ -0,0 +1,193 @@
+use crate::fixed::Fixed;
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, PointsIter},
+};
+
+/// Fixed capacity pool of up to `N` particles sharing a tiny bitmap.
+///
+/// Every particle has its own position, velocity, color and remaining
+/// lifetime, and is drawn as the bitmap tinted with its color. All particles
+/// are drawn in a single batch. Velocities are in pixels per second, the
+/// gravity in pixels per second squared and times in milliseconds.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Particles, bitmap, fixed::Fixed};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let spark = bitmap![" # ", "###", " # "];
+///
+/// let mut sparkles = Particles::<_, _, 16>::new(&spark).with_gravity(Fixed::from_int(20));
+/// sparkles.spawn(Point::new(8, 8), (Fixed::from_int(10), Fixed::from_int(-10)), Rgb565::YELLOW, 500);
+/// sparkles.spawn(Point::new(20, 8), (Fixed::ZERO, Fixed::ZERO), Rgb565::CYAN, 200);
+///
+/// sparkles.update(300);
+/// assert_eq!(sparkles.len(), 1);
+/// sparkles.draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Particles<'a, T, C, const N: usize>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: &'a T,
+    gravity: Fixed,
+    particles: [Option<Particle<C>>; N],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Particle<C> {
+    x: Fixed,
+    y: Fixed,
+    vx: Fixed,
+    vy: Fixed,
+    color: C,
+    life: u32,
+}
+
+impl<'a, T, C, const N: usize> Particles<'a, T, C, N>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create an empty pool of particles drawn as `image`
+    pub const fn new(image: &'a T) -> Self {
+        Self {
+            image,
+            gravity: Fixed::ZERO,
+            particles: [None; N],
+        }
+    }
+
+    /// Accelerate all particles downwards
+    ///
+    /// Negative values make particles rise.
+    pub const fn with_gravity(mut self, gravity: Fixed) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Add a particle with its top left corner at `position`
+    ///
+    /// The particle lives for `lifetime` milliseconds. Returns `false` if the
+    /// pool is full.
+    pub fn spawn(
+        &mut self,
+        position: Point,
+        velocity: (Fixed, Fixed),
+        color: C,
+        lifetime: u32,
+    ) -> bool {
+        let Some(slot) = self.particles.iter_mut().find(|slot| slot.is_none()) else {
+            return false;
+        };
+
+        *slot = Some(Particle {
+            x: Fixed::from_int(position.x),
+            y: Fixed::from_int(position.y),
+            vx: velocity.0,
+            vy: velocity.1,
+            color,
+            life: lifetime,
+        });
+        true
+    }
+
+    /// Returns the number of live particles
+    pub fn len(&self) -> usize {
+        self.particles.iter().flatten().count()
+    }
+
+    /// Returns `true` if there are no live particles
+    pub fn is_empty(&self) -> bool {
+        self.particles.iter().all(Option::is_none)
+    }
+
+    /// Remove all particles
+    pub fn clear(&mut self) {
+        self.particles = [None; N];
+    }
+
+    /// Advance all particles by `dt` milliseconds, removing expired ones
+    pub fn update(&mut self, dt: u32) {
+        for slot in &mut self.particles {
+            let Some(particle) = slot else {
+                continue;
+            };
+            if particle.life <= dt {
+                *slot = None;
+                continue;
+            }
+
+            particle.life -= dt;
+            particle.x = particle.x + particle.vx.over_millis(dt);
+            particle.y = particle.y + particle.vy.over_millis(dt);
+            particle.vy = particle.vy + self.gravity.over_millis(dt);
+        }
+    }
+}
+
+impl<T, C, const N: usize> Drawable for Particles<'_, T, C, N>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let bounds = target.bounding_box();
+        let image = self.image;
+        let pixels = self.particles.iter().flatten().flat_map(|particle| {
+            let position = Point::new(particle.x.round(), particle.y.round());
+            image
+                .bounding_box()
+                .points()
+                .filter(move |&point| image.pixel(point) == Some(BinaryColor::On))
+                .map(move |point| Pixel(position + point, particle.color))
+        });
+
+        target.draw_iter(pixels.filter(|Pixel(point, _)| bounds.contains(*point)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    #[test]
+    fn test_particles_move_and_expire() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let mut particles = Particles::<_, _, 2>::new(&raw).with_gravity(Fixed::from_int(10));
+
+        let still = (Fixed::ZERO, Fixed::ZERO);
+        assert!(particles.spawn(
+            Point::new(1, 0),
+            (Fixed::from_int(10), Fixed::ZERO),
+            BinaryColor::On,
+            300
+        ));
+        assert!(particles.spawn(Point::new(0, 0), still, BinaryColor::On, 100));
+        assert!(!particles.spawn(Point::new(0, 0), still, BinaryColor::On, 100));
+
+        particles.update(200);
+        assert_eq!(particles.len(), 1);
+        let mut display = MockDisplay::new();
+        particles.draw(&mut display).unwrap();
+        display.assert_pattern(&["   #"]);
+
+        particles.update(100);
+        assert!(particles.is_empty());
+    }
+}
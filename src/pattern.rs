@@ -0,0 +1,129 @@
+use crate::scanline::{self, Texture};
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Draw target adapter that fills binary shapes with a repeating texture.
+///
+/// Every `On` pixel drawn to a `PatternFill` is replaced with the texture
+/// pixel at the same position, drawn in the given color. `Off` pixels are
+/// skipped, so a primitive filled with `BinaryColor::On` becomes a textured
+/// shape.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::{BinaryColor, Rgb565},
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+/// };
+/// use embedded_graphics_colorcast::{PatternFill, bitmap};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let hatch = bitmap!["#.", ".#"];
+///
+/// Circle::new(Point::zero(), 16)
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut PatternFill::new(&mut display, &hatch, Rgb565::RED))?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct PatternFill<'a, D, T, C> {
+    target: &'a mut D,
+    texture: &'a T,
+    color: C,
+    background: Option<C>,
+}
+
+impl<'a, D, T, C> PatternFill<'a, D, T, C>
+where
+    D: DrawTarget<Color = C>,
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Fill shapes with a texture that is repeated from the origin
+    pub const fn new(target: &'a mut D, texture: &'a T, color: C) -> Self {
+        Self {
+            target,
+            texture,
+            color,
+            background: None,
+        }
+    }
+
+    /// Instead of skipping them, draw the `Off` pixels of the texture with given color
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+}
+
+impl<D, T, C> Dimensions for PatternFill<'_, D, T, C>
+where
+    D: DrawTarget<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D, T, C> DrawTarget for PatternFill<'_, D, T, C>
+where
+    D: DrawTarget<Color = C>,
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = BinaryColor;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let texture = Texture::new(self.texture, Point::zero());
+        let (color, background) = (self.color, self.background);
+        let shape = pixels
+            .into_iter()
+            .filter(|Pixel(_, shape)| shape.is_on())
+            .map(|Pixel(point, _)| point);
+
+        self.target
+            .draw_iter(scanline::fill(shape, texture, |_, c| match c {
+                BinaryColor::On => Some(color),
+                BinaryColor::Off => background,
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        Drawable,
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        pixelcolor::Rgb565,
+        prelude::{Primitive, RgbColor, Size},
+        primitives::PrimitiveStyle,
+    };
+
+    #[test]
+    fn test_pattern_fill_rectangle() {
+        let texture = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut display = MockDisplay::<Rgb565>::new();
+
+        Rectangle::new(Point::new(1, 0), Size::new(4, 2))
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(
+                &mut PatternFill::new(&mut display, &texture, Rgb565::RED)
+                    .with_background(Rgb565::BLUE),
+            )
+            .unwrap();
+        display.assert_pattern(&[" BRBR", " RBRB"]);
+    }
+}
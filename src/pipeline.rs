@@ -0,0 +1,184 @@
+use crate::Image;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{PixelColor, Point},
+};
+
+/// Receiver of rasterized rows, usually backed by a DMA channel or the second core.
+///
+/// [`Image::draw_pipelined`] fills one row buffer while the sink transfers
+/// the other one, which overlaps the rasterization with the transfer.
+pub trait RowSink<C> {
+    /// Error returned by the sink
+    type Error;
+
+    /// Start sending a row of pixels for the display row `y`
+    ///
+    /// This should return as soon as the transfer is started. The row stays
+    /// valid and unmodified until the next call to [`RowSink::wait`]
+    /// returns, so a DMA transfer can read straight from it.
+    fn start(&mut self, y: i32, row: &[C]) -> Result<(), Self::Error>;
+
+    /// Block until the transfer started last is complete
+    fn wait(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<C, S> RowSink<C> for &mut S
+where
+    S: RowSink<C> + ?Sized,
+{
+    type Error = S::Error;
+
+    fn start(&mut self, y: i32, row: &[C]) -> Result<(), Self::Error> {
+        (**self).start(y, row)
+    }
+
+    fn wait(&mut self) -> Result<(), Self::Error> {
+        (**self).wait()
+    }
+}
+
+/// Error returned by [`Image::draw_pipelined`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PipelineError<E> {
+    /// A row buffer is shorter than the width of the image
+    BufferTooSmall,
+    /// The sink failed
+    Sink(E),
+}
+
+impl<T, C> Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Rasterize the image row by row into two buffers that take turns
+    ///
+    /// While the sink sends one buffer, the next row is rasterized into the
+    /// other one. Every row covers the full width of the image, pixels that
+    /// aren't drawn are filled with `transparent`. This is meant for full
+    /// screen images on chips like the RP2040, where a DMA channel or the
+    /// second core can feed the display.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor, prelude::*};
+    /// use embedded_graphics_colorcast::{Image, RowSink};
+    ///
+    /// struct Dma;
+    ///
+    /// impl RowSink<BinaryColor> for Dma {
+    ///     type Error = ();
+    ///
+    ///     fn start(&mut self, _y: i32, _row: &[BinaryColor]) -> Result<(), ()> {
+    ///         // Start the transfer here
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn wait(&mut self) -> Result<(), ()> {
+    ///         // Wait for the transfer to complete here
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 16], 8);
+    /// let mut front = [BinaryColor::Off; 8];
+    /// let mut back = [BinaryColor::Off; 8];
+    ///
+    /// Image::new(&raw, Point::zero(), BinaryColor::On)
+    ///     .draw_pipelined(&mut Dma, [&mut front, &mut back], BinaryColor::Off)
+    ///     .unwrap();
+    /// ```
+    pub fn draw_pipelined<S>(
+        &self,
+        sink: &mut S,
+        buffers: [&mut [C]; 2],
+        transparent: C,
+    ) -> Result<(), PipelineError<S::Error>>
+    where
+        S: RowSink<C>,
+    {
+        let size = self.image.size();
+        let width = size.width as usize;
+        let [mut front, mut back] = buffers;
+        if front.len() < width || back.len() < width {
+            return Err(PipelineError::BufferTooSmall);
+        }
+
+        for y in 0..size.height as i32 {
+            for (x, pixel) in back[..width].iter_mut().enumerate() {
+                *pixel = self
+                    .pixel_at(Point::new(x as i32, y))
+                    .unwrap_or(transparent);
+            }
+
+            if y > 0 {
+                sink.wait().map_err(PipelineError::Sink)?;
+            }
+            sink.start(self.position.y + y, &back[..width])
+                .map_err(PipelineError::Sink)?;
+            core::mem::swap(&mut front, &mut back);
+        }
+
+        if size.height > 0 {
+            sink.wait().map_err(PipelineError::Sink)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::image::ImageRaw;
+
+    #[derive(Default)]
+    struct Recorder {
+        rows: [(i32, [BinaryColor; 2]); 2],
+        started: usize,
+        pending: bool,
+    }
+
+    impl RowSink<BinaryColor> for Recorder {
+        type Error = ();
+
+        fn start(&mut self, y: i32, row: &[BinaryColor]) -> Result<(), ()> {
+            assert!(!self.pending, "row started before wait");
+            self.rows[self.started] = (y, [row[0], row[1]]);
+            self.started += 1;
+            self.pending = true;
+            Ok(())
+        }
+
+        fn wait(&mut self) -> Result<(), ()> {
+            self.pending = false;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_draw_pipelined() {
+        use BinaryColor::{Off, On};
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut sink = Recorder::default();
+        let (mut front, mut back) = ([Off; 2], [Off; 3]);
+
+        Image::new(&raw, Point::new(0, 5), On)
+            .draw_pipelined(&mut sink, [&mut front, &mut back], Off)
+            .unwrap();
+        assert_eq!(sink.rows, [(5, [On, Off]), (6, [Off, On])]);
+        assert!(!sink.pending);
+
+        let mut short = [Off; 1];
+        assert_eq!(
+            Image::new(&raw, Point::zero(), On).draw_pipelined(
+                &mut sink,
+                [&mut short, &mut back],
+                Off
+            ),
+            Err(PipelineError::BufferTooSmall)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use crate::Image;
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, PixelColor, PointsIter, Transform},
+    primitives::{Rectangle, rectangle::Points},
+};
+
+/// Iterator over the pixels drawn by an [`Image`].
+///
+/// Returned by [`Image::pixels`]. The size hint is exact if every pixel of
+/// the image is drawn, which is the case for images with a background. Use
+/// [`Image::exact_pixels`] to get an [`ExactSizeIterator`] for those.
+#[derive(Debug)]
+pub struct Pixels<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    points: Points,
+    remaining: usize,
+}
+
+impl<'a, T, C> Pixels<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    pub(crate) fn new(image: Image<'a, T, C>) -> Self {
+        Self::with_area(image, image.image.bounding_box())
+    }
+
+    /// Only iterate the pixels that are inside `clip`, e.g. the bounds of the target
+    pub(crate) fn clipped(image: Image<'a, T, C>, clip: &Rectangle) -> Self {
+        let area = image.bounding_box().intersection(clip);
+        Self::with_area(image, area.translate(-image.position))
+    }
+
+    /// Iterate the source pixels inside `area`, which must be inside the source
+    fn with_area(image: Image<'a, T, C>, area: Rectangle) -> Self {
+        Self {
+            image,
+            points: area.points(),
+            remaining: area.size.width as usize * area.size.height as usize,
+        }
+    }
+}
+
+impl<T, C> Iterator for Pixels<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for point in self.points.by_ref() {
+            self.remaining -= 1;
+            if let Some(color) = self.image.pixel_at(point) {
+                return Some(Pixel(self.image.position + point, color));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = if self.image.is_opaque() {
+            self.remaining
+        } else {
+            0
+        };
+        (lower, Some(self.remaining))
+    }
+}
+
+impl<'a, T, C> IntoIterator for &Image<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+    type IntoIter = Pixels<'a, T, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pixels()
+    }
+}
+
+/// Iterator over the pixels of an [`Image`] that draws every pixel.
+///
+/// Returned by [`Image::exact_pixels`].
+#[derive(Debug)]
+pub struct ExactPixels<'a, T, C>(Pixels<'a, T, C>)
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor;
+
+impl<'a, T, C> ExactPixels<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    pub(crate) fn new(image: Image<'a, T, C>) -> Option<Self> {
+        image.is_opaque().then(|| Self(Pixels::new(image)))
+    }
+}
+
+impl<T, C> Iterator for ExactPixels<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T, C> ExactSizeIterator for ExactPixels<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, prelude::Point};
+
+    #[test]
+    fn test_size_hint() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0100_0000], 3);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+
+        let mut pixels = image.pixels();
+        assert_eq!(pixels.size_hint(), (0, Some(6)));
+        pixels.next();
+        assert_eq!(pixels.size_hint(), (0, Some(5)));
+        assert!(image.exact_pixels().is_none());
+
+        let image = image.with_background(BinaryColor::Off);
+        let mut pixels = image.exact_pixels().unwrap();
+        assert_eq!(pixels.len(), 6);
+        pixels.next();
+        assert_eq!(pixels.len(), 5);
+        assert_eq!(pixels.count(), 5);
+    }
+
+    #[test]
+    fn test_clipped() {
+        use embedded_graphics::prelude::Size;
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0100_0000], 3);
+        let image = Image::new(&raw, Point::new(-1, -1), BinaryColor::On);
+        let clip = Rectangle::new(Point::zero(), Size::new(8, 8));
+
+        let pixels = Pixels::clipped(image, &clip);
+        assert_eq!(pixels.size_hint(), (0, Some(2)));
+        assert!(pixels.eq([Pixel(Point::new(0, 0), BinaryColor::On)]));
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        use embedded_graphics::{mock_display::MockDisplay, prelude::DrawTarget};
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 3);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let cursor = Pixel(Point::new(1, 1), BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        display
+            .draw_iter(image.into_iter().chain(core::iter::once(cursor)))
+            .unwrap();
+        display.assert_pattern(&["# #", " # "]);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::Image;
+use core::ops::Range;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{PixelColor, Point},
+};
+
+/// Iterator over the rows of an [`Image`].
+///
+/// Returned by [`Image::rows`]. Every row is yielded as its `y` coordinate
+/// on the target together with the runs of `On` pixels in that row.
+#[derive(Debug)]
+pub struct Rows<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    y: u32,
+}
+
+impl<'a, T, C> Iterator for Rows<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = (i32, RowRuns<'a, T, C>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.image.image.size().height {
+            return None;
+        }
+
+        let row = RowRuns {
+            image: self.image,
+            y: self.y as i32,
+            x: 0,
+        };
+        self.y += 1;
+        Some((self.image.position.y + row.y, row))
+    }
+}
+
+/// Iterator over the runs of `On` pixels in a single row of an [`Image`].
+///
+/// The runs are ranges of `x` coordinates on the target, which are drawn in
+/// the color of the image. Pixels drawn in the background color aren't part
+/// of any run.
+#[derive(Debug)]
+pub struct RowRuns<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    y: i32,
+    x: u32,
+}
+
+impl<T, C> Iterator for RowRuns<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = Range<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let width = self.image.image.size().width;
+        let is_on = |x: u32| self.image.is_on(Point::new(x as i32, self.y));
+
+        let start = (self.x..width).find(|&x| is_on(x))?;
+        let end = (start..width).find(|&x| !is_on(x)).unwrap_or(width);
+        self.x = end;
+
+        let offset = self.image.position.x;
+        Some(offset + start as i32..offset + end as i32)
+    }
+}
+
+/// Iterator over the runs of `On` pixels of an [`Image`].
+///
+/// Returned by [`Image::runs`].
+#[derive(Debug)]
+pub struct Runs<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    rows: Rows<'a, T, C>,
+    row: Option<(i32, RowRuns<'a, T, C>)>,
+}
+
+impl<T, C> Iterator for Runs<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Item = (i32, Range<i32>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((y, runs)) = &mut self.row
+                && let Some(run) = runs.next()
+            {
+                return Some((*y, run));
+            }
+            self.row = Some(self.rows.next()?);
+        }
+    }
+}
+
+impl<'a, T, C> Image<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Returns an iterator over the rows and their runs of `On` pixels
+    pub fn rows(&self) -> Rows<'a, T, C> {
+        Rows { image: *self, y: 0 }
+    }
+
+    /// Returns an iterator over every run of `On` pixels as `(y, x_range)`
+    ///
+    /// Display drivers with a fast horizontal line primitive can draw the
+    /// runs in the color of the image, instead of drawing single pixels.
+    pub fn runs(&self) -> Runs<'a, T, C> {
+        Runs {
+            rows: self.rows(),
+            row: None,
+        }
+    }
+
+    /// Returns `true` if the source pixel at `point` is drawn in the image color
+    fn is_on(&self, point: Point) -> bool {
+        self.source_pixel(point) == Some(BinaryColor::On)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_runs() {
+        let image = AsciiImage::from_rows(&["##.#", "....", ".###"]).unwrap();
+        let image = Image::new(&image, Point::new(1, 2), BinaryColor::On);
+
+        let mut runs = image.runs();
+        assert_eq!(runs.next(), Some((2, 1..3)));
+        assert_eq!(runs.next(), Some((2, 4..5)));
+        assert_eq!(runs.next(), Some((4, 2..5)));
+        assert_eq!(runs.next(), None);
+
+        let mut rows = image.rows();
+        assert_eq!(rows.next().map(|(y, runs)| (y, runs.count())), Some((2, 2)));
+        assert_eq!(rows.next().map(|(y, runs)| (y, runs.count())), Some((3, 0)));
+        assert_eq!(rows.count(), 1);
+    }
+}
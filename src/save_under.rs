@@ -0,0 +1,218 @@
+use crate::Image;
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, PointsIter, Transform},
+};
+
+/// Sprite that saves the target pixels it covers and restores them on move.
+///
+/// This is the classic mouse cursor pattern for displays without hardware
+/// sprites. The target pixels under the sprite are read back before drawing,
+/// either from the target itself with [`SaveUnder::show`] or from a separate
+/// shadow framebuffer with [`SaveUnder::show_from`]. They're stored in a
+/// caller provided buffer with one color per sprite pixel.
+///
+/// Only the pixels that are actually drawn by the image are saved and
+/// restored.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     framebuffer::{Framebuffer, buffer_size},
+///     image::{GetPixel, ImageRaw},
+///     pixelcolor::{BinaryColor, raw::{BigEndian, RawU1}},
+///     prelude::*,
+/// };
+/// use embedded_graphics_colorcast::{Image, SaveUnder};
+///
+/// type Display =
+///     Framebuffer<BinaryColor, RawU1, BigEndian, 8, 8, { buffer_size::<BinaryColor>(8, 8) }>;
+/// let mut display = Display::new();
+///
+/// let cursor = ImageRaw::<BinaryColor>::new(&[0b1100_0000, 0b1000_0000], 2);
+/// let mut buffer = [BinaryColor::Off; 4];
+/// let image = Image::new(&cursor, Point::zero(), BinaryColor::On);
+/// let mut sprite = SaveUnder::new(image, &mut buffer).unwrap();
+///
+/// sprite.show(&mut display)?;
+/// sprite.move_to(&mut display, Point::new(4, 4))?;
+/// assert_eq!(display.pixel(Point::zero()), Some(BinaryColor::Off));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct SaveUnder<'a, 'b, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    buffer: &'b mut [C],
+    shown: bool,
+}
+
+impl<'a, 'b, T, C> SaveUnder<'a, 'b, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create a hidden sprite from an image
+    ///
+    /// Returns `None` if the buffer has fewer entries than the image has
+    /// pixels.
+    pub fn new(image: Image<'a, T, C>, buffer: &'b mut [C]) -> Option<Self> {
+        let size = image.image.size();
+        if buffer.len() < size.width as usize * size.height as usize {
+            return None;
+        }
+
+        Some(Self {
+            image,
+            buffer,
+            shown: false,
+        })
+    }
+
+    /// Returns the image of the sprite
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns `true` if the sprite is currently drawn
+    pub const fn is_shown(&self) -> bool {
+        self.shown
+    }
+
+    /// Save the pixels under the sprite and draw it, reading back from the target
+    ///
+    /// Does nothing if the sprite is already shown.
+    pub fn show<D>(&mut self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        if self.shown {
+            return Ok(());
+        }
+
+        self.save(target);
+        self.draw(target)
+    }
+
+    /// Save the pixels under the sprite and draw it, reading back from a shadow framebuffer
+    ///
+    /// The shadow has to hold the same content as the target.
+    pub fn show_from<D, S>(&mut self, target: &mut D, shadow: &S) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        S: GetPixel<Color = C>,
+    {
+        if self.shown {
+            return Ok(());
+        }
+
+        self.save(shadow);
+        self.draw(target)
+    }
+
+    /// Restore the saved pixels
+    ///
+    /// Does nothing if the sprite isn't shown.
+    pub fn hide<D>(&mut self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if !self.shown {
+            return Ok(());
+        }
+        self.shown = false;
+
+        let image = &self.image;
+        let buffer = &*self.buffer;
+        let width = image.image.size().width as usize;
+        target.draw_iter(
+            image
+                .image
+                .bounding_box()
+                .points()
+                .filter(|&point| image.pixel_at(point).is_some())
+                .map(|point| {
+                    let index = point.y as usize * width + point.x as usize;
+                    Pixel(image.position + point, buffer[index])
+                }),
+        )
+    }
+
+    /// Move the sprite, restoring the pixels at its old position
+    ///
+    /// The sprite is shown at the new position, even if it was hidden before.
+    pub fn move_to<D>(&mut self, target: &mut D, position: Point) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        self.hide(target)?;
+        self.image.translate_mut(position - self.image.position);
+        self.show(target)
+    }
+
+    /// Returns the image, releasing the buffer
+    pub fn into_inner(self) -> Image<'a, T, C> {
+        self.image
+    }
+
+    fn save<S>(&mut self, source: &S)
+    where
+        S: GetPixel<Color = C>,
+    {
+        let width = self.image.image.size().width as usize;
+        for point in self.image.image.bounding_box().points() {
+            if self.image.pixel_at(point).is_none() {
+                continue;
+            }
+            // Pixels outside the target are never drawn, so they don't
+            // need to be restored either.
+            if let Some(color) = source.pixel(self.image.position + point) {
+                self.buffer[point.y as usize * width + point.x as usize] = color;
+            }
+        }
+    }
+
+    fn draw<D>(&mut self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.shown = true;
+        self.image.draw(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        framebuffer::{Framebuffer, buffer_size},
+        image::ImageRaw,
+        pixelcolor::raw::{BigEndian, RawU1},
+    };
+
+    type Display =
+        Framebuffer<BinaryColor, RawU1, BigEndian, 8, 1, { buffer_size::<BinaryColor>(8, 1) }>;
+
+    #[test]
+    fn test_save_under_restores_background() {
+        let sprite = ImageRaw::<BinaryColor>::new(&[0b1010_0000], 3);
+        let mut display = Display::new();
+        display.data_mut()[0] = 0b0110_0110;
+
+        let mut buffer = [BinaryColor::Off; 3];
+        let image = Image::new(&sprite, Point::zero(), BinaryColor::On);
+        let mut sprite = SaveUnder::new(image, &mut buffer).unwrap();
+
+        sprite.show(&mut display).unwrap();
+        assert_eq!(display.data()[0], 0b1110_0110);
+        sprite.move_to(&mut display, Point::new(6, 0)).unwrap();
+        assert_eq!(display.data()[0], 0b0110_0110 | 0b0000_0010);
+        sprite.hide(&mut display).unwrap();
+        assert_eq!(display.data()[0], 0b0110_0110);
+    }
+}
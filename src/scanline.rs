@@ -0,0 +1,130 @@
+//! Texture fills for arbitrary regions.
+//!
+//! This is the core behind [`Image::tile_fill`](crate::Image::tile_fill) and
+//! [`PatternFill`](crate::PatternFill). It can be used directly to fill any
+//! region with a repeating binary texture and custom colors.
+
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{PixelColor, Point, PointsIter},
+};
+
+/// Binary image repeated infinitely in both directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Texture<'a, T> {
+    image: &'a T,
+    origin: Point,
+}
+
+impl<'a, T> Texture<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Repeat an image with one copy having its top left corner at `origin`
+    pub const fn new(image: &'a T, origin: Point) -> Self {
+        Self { image, origin }
+    }
+
+    /// Returns the point of the image that covers `point`
+    ///
+    /// Returns `None` if the image is empty.
+    pub fn texel(&self, point: Point) -> Option<Point> {
+        let size = self.image.size();
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        let offset = point - self.origin;
+        Some(Point::new(
+            offset.x.rem_euclid(size.width as i32),
+            offset.y.rem_euclid(size.height as i32),
+        ))
+    }
+}
+
+/// Fill points with a texture
+///
+/// `color` is called with the texel and its color for every point and
+/// returns the color of the pixel, or `None` to skip it.
+pub fn fill<'a, T, C, P, F>(
+    points: P,
+    texture: Texture<'a, T>,
+    mut color: F,
+) -> impl Iterator<Item = Pixel<C>> + 'a
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    P: IntoIterator<Item = Point>,
+    P::IntoIter: 'a,
+    F: FnMut(Point, BinaryColor) -> Option<C> + 'a,
+{
+    points.into_iter().filter_map(move |point| {
+        let texel = texture.texel(point)?;
+        let c = color(texel, texture.image.pixel(texel)?)?;
+        Some(Pixel(point, c))
+    })
+}
+
+/// Fill a region, like a primitive or rectangle, with a texture
+///
+/// See [`fill`] for how colors are picked.
+pub fn fill_region<'a, R, T, C, F>(
+    region: &'a R,
+    texture: Texture<'a, T>,
+    color: F,
+) -> impl Iterator<Item = Pixel<C>> + 'a
+where
+    R: PointsIter,
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    F: FnMut(Point, BinaryColor) -> Option<C> + 'a,
+{
+    fill(region.points(), texture, color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        Drawable,
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        prelude::{DrawTarget, Primitive},
+        primitives::{Circle, PrimitiveStyle},
+    };
+
+    #[test]
+    fn test_fill_region_circle() {
+        let texture = ImageRaw::<BinaryColor>::new(&[0b1000_0000], 2);
+        let circle = Circle::new(Point::new(0, 0), 5);
+        let mut display = MockDisplay::new();
+
+        display
+            .draw_iter(fill_region(
+                &circle,
+                Texture::new(&texture, Point::zero()),
+                |_, color| Some(color),
+            ))
+            .unwrap();
+        display.assert_pattern(&[" .#. ", "#.#.#", "#.#.#", "#.#.#", " .#. "]);
+
+        let mut expected = MockDisplay::new();
+        circle
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+        assert_eq!(display.affected_area(), expected.affected_area());
+    }
+
+    #[test]
+    fn test_texture_empty() {
+        let texture = crate::source::BinaryBuffer::new([0u8; 0], 0, 0).unwrap();
+        assert_eq!(
+            Texture::new(&texture, Point::zero()).texel(Point::zero()),
+            None
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{AnyTintedImage, geometry};
+use embedded_graphics::{
+    Drawable, Pixel,
+    draw_target::DrawTargetExt,
+    geometry::Dimensions,
+    prelude::{DrawTarget, PixelColor, Point, PointsIter, Transform},
+    primitives::Rectangle,
+};
+
+/// Batch of images that are drawn back to front.
+///
+/// With occlusion culling enabled, pixels that are covered by a later opaque
+/// image (see [`AnyTintedImage::is_opaque`]) are skipped. This reduces the
+/// number of pixels sent to slow serial displays, at the cost of checking
+/// every pixel against the images drawn after it.
+///
+/// Image positions are in world coordinates. The camera position is drawn at
+/// the top left corner of the target, so a scrolling world only needs to move
+/// the camera instead of every image.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{AnyTintedImage, Image, Scene};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xf0, 0xf0], 4);
+///
+/// let wallpaper = Image::new(&raw, Point::zero(), Rgb565::BLUE);
+/// let dialog = Image::new(&raw, Point::zero(), Rgb565::WHITE).with_background(Rgb565::BLACK);
+/// let images: [&dyn AnyTintedImage<Rgb565>; 2] = [&wallpaper, &dialog];
+///
+/// Scene::new(&images)
+///     .with_occlusion_culling(true)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct Scene<'a, C> {
+    images: &'a [&'a dyn AnyTintedImage<C>],
+    cull: bool,
+    camera: Point,
+}
+
+impl<'a, C> Scene<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a scene from images ordered back to front
+    pub const fn new(images: &'a [&'a dyn AnyTintedImage<C>]) -> Self {
+        Self {
+            images,
+            cull: false,
+            camera: Point::zero(),
+        }
+    }
+
+    /// Skip pixels that are hidden by later opaque images
+    pub const fn with_occlusion_culling(mut self, cull: bool) -> Self {
+        self.cull = cull;
+        self
+    }
+
+    /// Draw the world position `camera` at the top left corner of the target
+    pub const fn with_camera(mut self, camera: Point) -> Self {
+        self.camera = camera;
+        self
+    }
+
+    /// Returns the camera position
+    pub const fn camera(&self) -> Point {
+        self.camera
+    }
+
+    /// Returns the images of the scene
+    pub const fn images(&self) -> &'a [&'a dyn AnyTintedImage<C>] {
+        self.images
+    }
+
+    /// Returns the images whose areas intersect `area`, with their index
+    ///
+    /// The area is in target coordinates, so it's offset by the camera. The
+    /// images are returned back to front.
+    pub fn query(
+        &self,
+        area: Rectangle,
+    ) -> impl Iterator<Item = (usize, &'a dyn AnyTintedImage<C>)> + use<'a, C> {
+        let area = area.translate(self.camera);
+        self.images
+            .iter()
+            .enumerate()
+            .filter(move |(_, image)| geometry::overlaps(&image.area(), &area))
+            .map(|(index, &image)| (index, image))
+    }
+
+    /// Returns the index of the topmost image whose area contains `point`
+    ///
+    /// The point is in target coordinates, which makes this suitable for touch
+    /// hit testing.
+    pub fn query_point(&self, point: Point) -> Option<usize> {
+        let point = point + self.camera;
+        self.images
+            .iter()
+            .rposition(|image| image.area().contains(point))
+    }
+
+    /// Returns `true` if `point` is covered by an opaque image above `index`
+    fn is_occluded(&self, index: usize, point: Point) -> bool {
+        self.cull
+            && self.images[index + 1..]
+                .iter()
+                .any(|image| image.is_opaque() && image.area().contains(point))
+    }
+
+    /// Returns `true` if the whole area is covered by a single opaque image above `index`
+    fn is_hidden(&self, index: usize, area: &Rectangle) -> bool {
+        self.cull
+            && self.images[index + 1..]
+                .iter()
+                .any(|image| image.is_opaque() && image.area().intersection(area) == *area)
+    }
+}
+
+impl<C> Drawable for Scene<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut target = target.translated(-self.camera);
+        for (index, image) in self.images.iter().enumerate() {
+            let area = image.area();
+            if self.is_hidden(index, &area) {
+                continue;
+            }
+
+            target.draw_iter(area.points().filter_map(|point| {
+                if self.is_occluded(index, point) {
+                    return None;
+                }
+                Some(Pixel(point, image.color_at(point)?))
+            }))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Dimensions for Scene<'_, C>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.images
+            .iter()
+            .fold(Rectangle::zero(), |area, image| {
+                geometry::union(&area, &image.area())
+            })
+            .translate(-self.camera)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{
+        image::ImageRaw, mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::Size,
+    };
+
+    #[test]
+    fn test_occlusion_culling() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1100_0000], 2);
+        let below = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let above =
+            Image::new(&raw, Point::new(1, 0), BinaryColor::Off).with_background(BinaryColor::On);
+        let images: [&dyn AnyTintedImage<BinaryColor>; 2] = [&below, &above];
+
+        // Without culling, the overlapping pixel would be drawn twice and
+        // the mock display would panic.
+        let mut display = MockDisplay::new();
+        Scene::new(&images)
+            .with_occlusion_culling(true)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["#.."]);
+    }
+
+    #[test]
+    fn test_camera_offset() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1000_0000], 1);
+        let image = Image::new(&raw, Point::new(10, 5), BinaryColor::On);
+        let images: [&dyn AnyTintedImage<BinaryColor>; 1] = [&image];
+        let scene = Scene::new(&images).with_camera(Point::new(8, 5));
+
+        let mut display = MockDisplay::new();
+        scene.draw(&mut display).unwrap();
+        display.assert_pattern(&["  #"]);
+        assert_eq!(scene.bounding_box().top_left, Point::new(2, 0));
+    }
+
+    #[test]
+    fn test_query() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xc0, 0xc0], 2);
+        let left = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let right = Image::new(&raw, Point::new(1, 0), BinaryColor::On);
+        let images: [&dyn AnyTintedImage<BinaryColor>; 2] = [&left, &right];
+        let scene = Scene::new(&images).with_camera(Point::new(1, 0));
+
+        let area = Rectangle::new(Point::new(1, 0), Size::new(2, 2));
+        let found: [Option<usize>; 2] = {
+            let mut query = scene.query(area).map(|(index, _)| index);
+            [query.next(), query.next()]
+        };
+        assert_eq!(found, [Some(1), None]);
+        assert_eq!(scene.query_point(Point::zero()), Some(1));
+        assert_eq!(scene.query_point(Point::new(-1, 0)), Some(0));
+        assert_eq!(scene.query_point(Point::new(5, 0)), None);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{AnyTintedImage, Scene, geometry};
+use embedded_graphics::{
+    Drawable,
+    draw_target::DrawTargetExt,
+    prelude::{DrawTarget, PixelColor, Size},
+    primitives::Rectangle,
+};
+
+/// Tracks the areas of up to `N` images between frames.
+///
+/// Every image has a slot that holds the area it was drawn at. Moving,
+/// changing or removing an image marks both its old and its new area as
+/// dirty. Overlapping dirty areas are merged, and if more than `N` areas are
+/// dirty the pair that grows the least is merged, so a frame never redraws
+/// more than `N` rectangles.
+///
+/// Images that changed in place and are drawn with a background can also be
+/// updated with [`diff`](crate::diff) instead of invalidating their slot.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{AnyTintedImage, FrameScheduler, Image};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xf0], 4);
+/// let mut scheduler = FrameScheduler::<4>::new();
+///
+/// let mut cursor = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+/// scheduler.set_area(0, cursor.bounding_box());
+/// # display.set_allow_overdraw(true);
+/// scheduler.redraw(&mut display, Rgb565::BLACK, &[&cursor])?;
+///
+/// cursor.translate_mut(Point::new(2, 0));
+/// scheduler.set_area(0, cursor.bounding_box());
+/// assert_eq!(scheduler.dirty().len(), 1);
+/// scheduler.redraw(&mut display, Rgb565::BLACK, &[&cursor])?;
+/// assert!(scheduler.dirty().is_empty());
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameScheduler<const N: usize> {
+    drawn: [Rectangle; N],
+    dirty: [Rectangle; N],
+    dirty_len: usize,
+}
+
+impl<const N: usize> FrameScheduler<N> {
+    /// Create a scheduler with empty slots
+    pub const fn new() -> Self {
+        Self {
+            drawn: [Rectangle::zero(); N],
+            dirty: [Rectangle::zero(); N],
+            dirty_len: 0,
+        }
+    }
+
+    /// Set the area of an image, marking the old and new area dirty if it moved
+    ///
+    /// Slots out of range are ignored.
+    pub fn set_area(&mut self, slot: usize, area: Rectangle) {
+        let Some(drawn) = self.drawn.get_mut(slot) else {
+            return;
+        };
+        if *drawn == area {
+            return;
+        }
+
+        let old = core::mem::replace(drawn, area);
+        self.mark_dirty(old);
+        self.mark_dirty(area);
+    }
+
+    /// Mark the area of an image dirty because its content changed
+    pub fn invalidate(&mut self, slot: usize) {
+        if let Some(&area) = self.drawn.get(slot) {
+            self.mark_dirty(area);
+        }
+    }
+
+    /// Clear a slot, marking the area of its image dirty so it gets erased
+    pub fn remove(&mut self, slot: usize) {
+        self.set_area(slot, Rectangle::zero());
+    }
+
+    /// Add an area that needs to be redrawn
+    ///
+    /// Zero sized areas are ignored.
+    pub fn mark_dirty(&mut self, area: Rectangle) {
+        if area.is_zero_sized() {
+            return;
+        }
+
+        let mut area = area;
+        // Merging can make the area overlap others that it didn't before.
+        while let Some(index) = self
+            .dirty()
+            .iter()
+            .position(|dirty| geometry::overlaps(dirty, &area))
+        {
+            area = geometry::union(&area, &self.take(index));
+        }
+
+        if self.dirty_len == N {
+            let Some(index) = self.cheapest_merge(&area) else {
+                return;
+            };
+            let merged = geometry::union(&area, &self.take(index));
+            return self.mark_dirty(merged);
+        }
+
+        self.dirty[self.dirty_len] = area;
+        self.dirty_len += 1;
+    }
+
+    /// Returns the areas that need to be redrawn
+    pub fn dirty(&self) -> &[Rectangle] {
+        &self.dirty[..self.dirty_len]
+    }
+
+    /// Forget the dirty areas after they have been redrawn
+    pub fn finish_frame(&mut self) {
+        self.dirty_len = 0;
+    }
+
+    /// Redraw the dirty areas and finish the frame
+    ///
+    /// Every dirty area is cleared with `background` and the images, ordered
+    /// back to front, are drawn clipped to it.
+    pub fn redraw<D, C>(
+        &mut self,
+        target: &mut D,
+        background: C,
+        images: &[&dyn AnyTintedImage<C>],
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor,
+    {
+        for area in self.dirty() {
+            let mut clipped = target.clipped(area);
+            clipped.fill_solid(area, background)?;
+            Scene::new(images).draw(&mut clipped)?;
+        }
+
+        self.finish_frame();
+        Ok(())
+    }
+
+    /// Remove a dirty area by index
+    fn take(&mut self, index: usize) -> Rectangle {
+        let area = self.dirty[index];
+        self.dirty_len -= 1;
+        self.dirty[index] = self.dirty[self.dirty_len];
+        area
+    }
+
+    /// Returns the dirty area that grows the least when merged with `area`
+    fn cheapest_merge(&self, area: &Rectangle) -> Option<usize> {
+        self.dirty()
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, dirty)| {
+                let merged = geometry::union(area, dirty).size;
+                pixels(merged) - pixels(dirty.size)
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+impl<const N: usize> Default for FrameScheduler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn pixels(size: Size) -> u64 {
+    u64::from(size.width) * u64::from(size.height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::prelude::Point;
+
+    fn rect(x: i32, y: i32, w: u32, h: u32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(w, h))
+    }
+
+    #[test]
+    fn test_move_marks_old_and_new_area() {
+        let mut scheduler = FrameScheduler::<4>::new();
+        scheduler.set_area(0, rect(0, 0, 2, 2));
+        scheduler.finish_frame();
+
+        scheduler.set_area(0, rect(10, 0, 2, 2));
+        assert_eq!(scheduler.dirty(), [rect(0, 0, 2, 2), rect(10, 0, 2, 2)]);
+
+        scheduler.set_area(1, rect(1, 1, 2, 2));
+        assert_eq!(scheduler.dirty(), [rect(10, 0, 2, 2), rect(0, 0, 3, 3)]);
+    }
+
+    #[test]
+    fn test_merge_when_full() {
+        let mut scheduler = FrameScheduler::<2>::new();
+        scheduler.mark_dirty(rect(0, 0, 1, 1));
+        scheduler.mark_dirty(rect(20, 0, 1, 1));
+        scheduler.mark_dirty(rect(2, 0, 1, 1));
+        assert_eq!(scheduler.dirty(), [rect(20, 0, 1, 1), rect(0, 0, 3, 1)]);
+    }
+}
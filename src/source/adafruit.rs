@@ -0,0 +1,124 @@
+use super::{
+    PixelDataReader,
+    bits::{self, BitOrder, Bits},
+};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Bitmap in the layout used by Adafruit GFX `drawBitmap`.
+///
+/// Pixels are stored MSB-first with every row padded to a full byte, which
+/// means icon arrays written for Arduino sketches can be used unmodified.
+/// Unlike [`ImageRaw`](embedded_graphics::image::ImageRaw) the height is
+/// given explicitly, just like in the C API.
+///
+/// Bitmaps in `PROGMEM` or other memory that can't be read directly can be
+/// used with [`AdafruitBitmap::from_reader`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AdafruitBitmap<'a, R: ?Sized = [u8]> {
+    bits: Bits<'a, R>,
+    size: Size,
+}
+
+impl<R: ?Sized> Clone for AdafruitBitmap<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: ?Sized> Copy for AdafruitBitmap<'_, R> {}
+
+impl<'a> AdafruitBitmap<'a> {
+    /// Create a new bitmap from `drawBitmap` compatible data
+    ///
+    /// If `data` holds fewer than `height` rows, the height is reduced to
+    /// the number of complete rows.
+    pub const fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        let bits = Bits::new(data, width, BitOrder::MsbFirst);
+        Self {
+            bits,
+            size: bits.clamp(Size::new(width, height)),
+        }
+    }
+}
+
+impl<'a, R> AdafruitBitmap<'a, R>
+where
+    R: PixelDataReader + ?Sized,
+{
+    /// Create a new bitmap reading its data through a [`PixelDataReader`]
+    ///
+    /// The height is reduced like in [`AdafruitBitmap::new`].
+    pub fn from_reader(data: &'a R, width: u32, height: u32) -> Self {
+        let bits = Bits::new(data, width, BitOrder::MsbFirst);
+        Self {
+            bits,
+            size: bits.clamp_len(data.len(), Size::new(width, height)),
+        }
+    }
+}
+
+impl<R: ?Sized> OriginDimensions for AdafruitBitmap<'_, R> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<R> GetPixel for AdafruitBitmap<'_, R>
+where
+    R: PixelDataReader + ?Sized,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits.get(p).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adafruit_rows_are_byte_aligned() {
+        let bitmap = AdafruitBitmap::new(&[0b1010_0000, 0b0110_0000], 3, 2);
+        assert_eq!(bitmap.size(), Size::new(3, 2));
+        assert_eq!(bitmap.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(bitmap.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(bitmap.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert_eq!(bitmap.pixel(Point::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_adafruit_height_is_clamped() {
+        let bitmap = AdafruitBitmap::new(&[0xff, 0xff], 16, 4);
+        assert_eq!(bitmap.size(), Size::new(16, 1));
+    }
+
+    #[test]
+    fn test_adafruit_from_reader() {
+        /// Reader for data stored inverted, to tell it apart from a slice
+        struct Inverted([u8; 2]);
+
+        impl PixelDataReader for Inverted {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            fn read(&self, index: usize) -> u8 {
+                !self.0[index]
+            }
+        }
+
+        let data = Inverted([0b0101_1111, 0b1001_1111]);
+        let bitmap = AdafruitBitmap::from_reader(&data, 3, 4);
+        assert_eq!(bitmap.size(), Size::new(3, 2));
+        assert_eq!(bitmap.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(bitmap.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(bitmap.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+    }
+}
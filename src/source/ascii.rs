@@ -0,0 +1,214 @@
+use super::bits;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Create a binary image from ASCII art at compile time.
+///
+/// Every string literal is one row of the image. `#` and `X` are `On` pixels,
+/// `.` and spaces are `Off` pixels. All rows must have the same length,
+/// otherwise compilation fails.
+///
+/// The macro expands to an `ImageRaw<'static, BinaryColor>` expression.
+///
+/// ```rust
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::bitmap;
+///
+/// let arrow = bitmap![
+///     "..#..",
+///     ".###.",
+///     "#####",
+/// ];
+///
+/// assert_eq!(arrow.size(), Size::new(5, 3));
+/// assert_eq!(arrow.pixel(Point::new(2, 0)), Some(BinaryColor::On));
+/// ```
+#[macro_export]
+macro_rules! bitmap {
+    ($($row:literal),+ $(,)?) => {{
+        const ROWS: &[&str] = &[$($row),+];
+        const WIDTH: usize = $crate::__private::ascii_width(ROWS);
+        static DATA: [u8; WIDTH.div_ceil(8) * ROWS.len()] = $crate::__private::ascii_pack(ROWS);
+        $crate::__private::ImageRaw::<$crate::__private::BinaryColor>::new(&DATA, WIDTH as u32)
+    }};
+}
+
+/// Returns the width of an ASCII art image, panicking if rows differ in length
+#[doc(hidden)]
+pub const fn width(rows: &[&str]) -> usize {
+    let width = if rows.is_empty() { 0 } else { rows[0].len() };
+
+    let mut y = 0;
+    while y < rows.len() {
+        assert!(rows[y].len() == width, "all rows must have the same length");
+        y += 1;
+    }
+
+    width
+}
+
+/// Pack ASCII art into MSB-first 1bpp data with byte-aligned rows
+#[doc(hidden)]
+pub const fn pack<const N: usize>(rows: &[&str]) -> [u8; N] {
+    let stride = width(rows).div_ceil(8);
+    let mut data = [0; N];
+
+    let mut y = 0;
+    while y < rows.len() {
+        let row = rows[y].as_bytes();
+        let mut x = 0;
+        while x < row.len() {
+            if is_on(row[x]) {
+                data[y * stride + x / 8] |= 0x80 >> (x % 8);
+            }
+            x += 1;
+        }
+        y += 1;
+    }
+
+    data
+}
+
+const fn is_on(c: u8) -> bool {
+    match parse(c) {
+        Some(on) => on,
+        None => panic!("invalid character, expected one of `#`, `X`, `.` or ` `"),
+    }
+}
+
+const fn parse(c: u8) -> Option<bool> {
+    match c {
+        b'#' | b'X' => Some(true),
+        b'.' | b' ' => Some(false),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Rows<'a> {
+    /// Rows separated by `\n`
+    Text(&'a str),
+    Slice(&'a [&'a str]),
+}
+
+/// Binary image backed by ASCII art.
+///
+/// This is the runtime counterpart to [`bitmap!`](crate::bitmap): `#` and `X`
+/// are `On` pixels, `.` and spaces are `Off` pixels. It's meant for quick
+/// prototyping and tests, since every pixel is looked up in the text.
+///
+/// ```rust
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::source::AsciiImage;
+///
+/// let image = AsciiImage::new("#.\n.#").unwrap();
+/// assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiImage<'a> {
+    rows: Rows<'a>,
+    size: Size,
+}
+
+impl<'a> AsciiImage<'a> {
+    /// Parse rows separated by newlines
+    ///
+    /// A single trailing newline is ignored. Returns `None` if the rows have
+    /// different lengths or contain other characters.
+    pub fn new(text: &'a str) -> Option<Self> {
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        let size = validate(text.split('\n'))?;
+        Some(Self {
+            rows: Rows::Text(text),
+            size,
+        })
+    }
+
+    /// Use the rows of a pattern, like the ones passed to
+    /// [`MockDisplay::assert_pattern`](embedded_graphics::mock_display::MockDisplay::assert_pattern)
+    ///
+    /// Returns `None` if the rows have different lengths or contain other
+    /// characters.
+    pub fn from_rows(rows: &'a [&'a str]) -> Option<Self> {
+        let size = validate(rows.iter().copied())?;
+        Some(Self {
+            rows: Rows::Slice(rows),
+            size,
+        })
+    }
+}
+
+fn validate<'a>(rows: impl Iterator<Item = &'a str>) -> Option<Size> {
+    let mut width = None;
+    let mut height = 0;
+
+    for row in rows {
+        if *width.get_or_insert(row.len()) != row.len() || !row.bytes().all(|c| parse(c).is_some())
+        {
+            return None;
+        }
+        height += 1;
+    }
+
+    Some(Size::new(width.unwrap_or(0) as u32, height))
+}
+
+impl OriginDimensions for AsciiImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for AsciiImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let (x, y) = (p.x as usize, p.y as usize);
+        let c = match self.rows {
+            Rows::Text(text) => text.as_bytes()[y * (self.size.width as usize + 1) + x],
+            Rows::Slice(rows) => rows[y].as_bytes()[x],
+        };
+        parse(c).map(BinaryColor::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap_macro() {
+        let image = bitmap!["..XX......", "XXXX.....#"];
+        assert_eq!(image.size(), Size::new(10, 2));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(2, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(9, 1)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn test_ascii_image() {
+        let text = AsciiImage::new("..#\n##.\n").unwrap();
+        let rows = AsciiImage::from_rows(&["..#", "##."]).unwrap();
+        assert_eq!(text.size(), Size::new(3, 2));
+        for image in [text, rows] {
+            assert_eq!(image.pixel(Point::new(2, 0)), Some(BinaryColor::On));
+            assert_eq!(image.pixel(Point::new(2, 1)), Some(BinaryColor::Off));
+            assert_eq!(image.pixel(Point::new(0, 1)), Some(BinaryColor::On));
+            assert_eq!(image.pixel(Point::new(0, 2)), None);
+        }
+    }
+
+    #[test]
+    fn test_ascii_image_invalid() {
+        assert!(AsciiImage::new("..\n...").is_none());
+        assert!(AsciiImage::from_rows(&["ab"]).is_none());
+    }
+}
@@ -0,0 +1,161 @@
+use super::bits;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Font in the Glyph Bitmap Distribution Format (BDF).
+///
+/// BDF is the source format of most bitmap fonts, including the u8g2 and
+/// `embedded-graphics` fonts. This type doesn't render text, instead it
+/// extracts individual glyphs as binary images so large symbols can be
+/// tinted and positioned like any other image.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Image, source::BdfFont};
+///
+/// let font = BdfFont::new(include_str!("../../testdata/symbols.bdf"));
+/// let sun = font.glyph('*').unwrap();
+/// let image = Image::new(&sun, Point::zero(), Rgb565::YELLOW);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BdfFont<'a> {
+    text: &'a str,
+}
+
+impl<'a> BdfFont<'a> {
+    /// Wrap the contents of a BDF file
+    pub const fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+
+    /// Look up the glyph for a character
+    ///
+    /// Returns `None` if the font has no glyph with this encoding or the glyph
+    /// is malformed.
+    pub fn glyph(&self, c: char) -> Option<BdfGlyph<'a>> {
+        self.text
+            .split("STARTCHAR")
+            .skip(1)
+            .find(|block| property(block, "ENCODING") == Some(c as i32))
+            .and_then(BdfGlyph::parse)
+    }
+}
+
+/// Single glyph of a [`BdfFont`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BdfGlyph<'a> {
+    bitmap: &'a str,
+    /// Length of a bitmap line including the line ending
+    stride: usize,
+    size: Size,
+    offset: Point,
+    advance: i32,
+}
+
+impl<'a> BdfGlyph<'a> {
+    fn parse(block: &'a str) -> Option<Self> {
+        let mut bbx = block
+            .lines()
+            .find_map(|line| line.strip_prefix("BBX "))?
+            .split_whitespace();
+        let mut next = || bbx.next()?.parse::<i32>().ok();
+        let (width, height, x, y) = (next()?, next()?, next()?, next()?);
+        let advance = property(block, "DWIDTH").unwrap_or(width);
+
+        let start = block.find("BITMAP")?;
+        let bitmap = block[start..].split_once('\n')?.1;
+        let bitmap = &bitmap[..bitmap.find("ENDCHAR")?];
+
+        // All rows have the same length, so pixels can be looked up directly.
+        let stride = bitmap.find('\n')? + 1;
+        let digits = (width as usize).div_ceil(8) * 2;
+        let mut rows = bitmap.split_terminator('\n');
+        if stride <= digits
+            || rows.clone().count() < height as usize
+            || !rows.all(|row| row.len() == stride - 1)
+        {
+            return None;
+        }
+
+        Some(Self {
+            bitmap,
+            stride,
+            size: Size::new(width as u32, height as u32),
+            offset: Point::new(x, y),
+            advance,
+        })
+    }
+
+    /// Offset of the glyph bitmap relative to the origin, as given by `BBX`
+    ///
+    /// BDF uses a y axis pointing up, so `y` is the distance from the baseline
+    /// to the bottom row of the bitmap.
+    pub const fn offset(&self) -> Point {
+        self.offset
+    }
+
+    /// Horizontal distance to the next glyph, as given by `DWIDTH`
+    pub const fn advance(&self) -> i32 {
+        self.advance
+    }
+}
+
+impl OriginDimensions for BdfGlyph<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for BdfGlyph<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let index = p.y as usize * self.stride + p.x as usize / 4;
+        let digit = char::from(*self.bitmap.as_bytes().get(index)?).to_digit(16)?;
+        Some((digit & (0x8 >> (p.x % 4)) != 0).into())
+    }
+}
+
+/// Returns the first numeric argument of a property line
+fn property(block: &str, name: &str) -> Option<i32> {
+    block
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.strip_prefix(' '))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FONT: &str = include_str!("../../testdata/symbols.bdf");
+
+    #[test]
+    fn test_bdf_glyph() {
+        let font = BdfFont::new(FONT);
+        let glyph = font.glyph('*').unwrap();
+        assert_eq!(glyph.size(), Size::new(5, 5));
+        assert_eq!(glyph.offset(), Point::new(0, -1));
+        assert_eq!(glyph.advance(), 6);
+        assert_eq!(glyph.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(glyph.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(glyph.pixel(Point::new(2, 2)), Some(BinaryColor::On));
+        assert_eq!(glyph.pixel(Point::new(5, 0)), None);
+    }
+
+    #[test]
+    fn test_bdf_missing_glyph() {
+        assert!(BdfFont::new(FONT).glyph('A').is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use super::PixelDataReader;
+use embedded_graphics::prelude::{Point, Size};
+
+/// Order of the pixels within a single byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BitOrder {
+    /// The leftmost pixel is stored in the most significant bit
+    #[default]
+    MsbFirst,
+    /// The leftmost pixel is stored in the least significant bit
+    LsbFirst,
+}
+
+/// Row-major packed 1bpp pixel data with byte-aligned rows.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Bits<'a, R: ?Sized = [u8]> {
+    data: &'a R,
+    stride: usize,
+    order: BitOrder,
+}
+
+impl<R: ?Sized> Clone for Bits<'_, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: ?Sized> Copy for Bits<'_, R> {}
+
+impl<'a, R: ?Sized> Bits<'a, R> {
+    /// Create a view for rows of `width` pixels, padded to a full byte
+    pub(crate) const fn new(data: &'a R, width: u32, order: BitOrder) -> Self {
+        Self {
+            data,
+            stride: width.div_ceil(8) as usize,
+            order,
+        }
+    }
+
+    /// Change the order of the pixels within a byte
+    pub(crate) const fn with_order(mut self, order: BitOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Change the number of bytes between the starts of two rows
+    pub(crate) const fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    /// Clamp the requested size to the rows present in `len` bytes of data
+    pub(crate) const fn clamp_len(&self, len: usize, size: Size) -> Size {
+        let rows = match len.checked_div(self.stride) {
+            Some(rows) => rows as u32,
+            None => 0,
+        };
+        let height = if size.height < rows {
+            size.height
+        } else {
+            rows
+        };
+        Size::new(size.width, height)
+    }
+}
+
+impl Bits<'_> {
+    /// Clamp the requested size to the rows actually present in the data
+    pub(crate) const fn clamp(&self, size: Size) -> Size {
+        self.clamp_len(self.data.len(), size)
+    }
+}
+
+impl<R: PixelDataReader + ?Sized> Bits<'_, R> {
+    /// Read the bit at `point`, which must be inside the image
+    pub(crate) fn get(&self, point: Point) -> bool {
+        let index = point.y as usize * self.stride + point.x as usize / 8;
+        let bit = point.x as u32 % 8;
+        let mask = match self.order {
+            BitOrder::MsbFirst => 0x80 >> bit,
+            BitOrder::LsbFirst => 0x01 << bit,
+        };
+        index < self.data.len() && self.data.read(index) & mask != 0
+    }
+
+    /// Returns `true` if none of the first `width` bits of row `y` is set
+    ///
+    /// Padding bits at the end of the row are ignored.
+    pub(crate) fn is_row_blank(&self, y: u32, width: u32) -> bool {
+        let start = y as usize * self.stride;
+        let full = width as usize / 8;
+        let read = |index: usize| {
+            if index < self.data.len() {
+                self.data.read(index)
+            } else {
+                0
+            }
+        };
+
+        let padding = 8 - width % 8;
+        let mask = match self.order {
+            _ if padding == 8 => 0,
+            BitOrder::MsbFirst => 0xff << padding,
+            BitOrder::LsbFirst => 0xff >> padding,
+        };
+        (start..start + full).all(|index| read(index) == 0) && read(start + full) & mask == 0
+    }
+}
+
+/// Returns `true` if `point` lies inside an image of the given size
+pub(crate) const fn contains(size: Size, point: Point) -> bool {
+    point.x >= 0 && point.y >= 0 && (point.x as u32) < size.width && (point.y as u32) < size.height
+}
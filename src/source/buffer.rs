@@ -0,0 +1,231 @@
+use super::{
+    RowSummary,
+    bits::{self, BitOrder, Bits},
+};
+#[cfg(feature = "std")]
+use crate::view::LumaWeights;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::{GetPixel, ImageRaw},
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+#[cfg(feature = "std")]
+use embedded_graphics::{mock_display::MockDisplay, prelude::PointsIter, primitives::Rectangle};
+#[cfg(feature = "std")]
+use std::{path::Path, vec, vec::Vec};
+
+/// Binary image with owned pixel data.
+///
+/// The data is stored MSB-first with byte-aligned rows, the same layout as
+/// [`ImageRaw<BinaryColor>`]. `B` can be any byte storage, like an array or
+/// a `Vec<u8>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BinaryBuffer<B> {
+    data: B,
+    size: Size,
+}
+
+impl<B> BinaryBuffer<B>
+where
+    B: AsRef<[u8]>,
+{
+    /// Number of bytes needed to store an image of the given size
+    pub const fn data_len(width: u32, height: u32) -> usize {
+        width.div_ceil(8) as usize * height as usize
+    }
+
+    /// Wrap existing pixel data
+    ///
+    /// Returns `None` if `data` is too short for the given size.
+    pub fn new(data: B, width: u32, height: u32) -> Option<Self> {
+        (data.as_ref().len() >= Self::data_len(width, height)).then_some(Self {
+            data,
+            size: Size::new(width, height),
+        })
+    }
+
+    /// Returns the packed pixel data
+    pub fn data(&self) -> &[u8] {
+        &self.data.as_ref()[..Self::data_len(self.size.width, self.size.height)]
+    }
+
+    /// Returns the underlying storage
+    pub fn into_inner(self) -> B {
+        self.data
+    }
+
+    /// Borrow the buffer as an `ImageRaw`
+    pub fn as_raw(&self) -> ImageRaw<'_, BinaryColor> {
+        ImageRaw::new(self.data(), self.size.width)
+    }
+
+    fn bits(&self) -> Bits<'_> {
+        Bits::new(self.data.as_ref(), self.size.width, BitOrder::MsbFirst)
+    }
+}
+
+impl<B> BinaryBuffer<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Returns the packed pixel data for modification
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let len = Self::data_len(self.size.width, self.size.height);
+        &mut self.data.as_mut()[..len]
+    }
+
+    /// Change the color of a pixel
+    ///
+    /// Points outside the image are ignored.
+    pub fn set_pixel(&mut self, p: Point, color: BinaryColor) {
+        if !bits::contains(self.size, p) {
+            return;
+        }
+
+        let index = p.y as usize * self.size.width.div_ceil(8) as usize + p.x as usize / 8;
+        let mask = 0x80 >> (p.x % 8);
+        let byte = &mut self.data.as_mut()[index];
+        if color.is_on() {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl BinaryBuffer<Vec<u8>> {
+    /// Allocate a new image with all pixels set to `Off`
+    pub fn blank(width: u32, height: u32) -> Self {
+        Self {
+            data: vec![0; Self::data_len(width, height)],
+            size: Size::new(width, height),
+        }
+    }
+
+    /// Convert a decoded image to a binary image
+    ///
+    /// Pixels with a BT.601 luma of at least `threshold` become `On`, darker
+    /// and transparent pixels become `Off`.
+    pub fn from_image(image: &image::DynamicImage, threshold: u8) -> Self {
+        let image = image.to_rgba8();
+        let (width, height) = image.dimensions();
+
+        let mut buffer = Self::blank(width, height);
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let [r, g, b, a] = pixel.0;
+            let luma = LumaWeights::BT601.luma(r, g, b);
+            let color = BinaryColor::from(a >= 128 && luma >= threshold);
+            buffer.set_pixel(Point::new(x as i32, y as i32), color);
+        }
+        buffer
+    }
+
+    /// Load a PNG or BMP file and convert it to a binary image
+    ///
+    /// See [`BinaryBuffer::from_image`] for how pixels are thresholded.
+    pub fn open(path: impl AsRef<Path>, threshold: u8) -> Result<Self, image::ImageError> {
+        Ok(Self::from_image(&image::open(path)?, threshold))
+    }
+
+    /// Decode a PNG or BMP file from memory and convert it to a binary image
+    ///
+    /// See [`BinaryBuffer::from_image`] for how pixels are thresholded.
+    pub fn from_memory(data: &[u8], threshold: u8) -> Result<Self, image::ImageError> {
+        Ok(Self::from_image(&image::load_from_memory(data)?, threshold))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&MockDisplay<BinaryColor>> for BinaryBuffer<Vec<u8>> {
+    /// Copy the pixels of a mock display into a new image
+    ///
+    /// The image extends from the origin to the bottom right corner of the
+    /// drawn area, undrawn pixels are `Off`.
+    fn from(display: &MockDisplay<BinaryColor>) -> Self {
+        let size = display
+            .affected_area()
+            .bottom_right()
+            .map_or(Size::zero(), |p| Size::new(p.x as u32 + 1, p.y as u32 + 1));
+
+        let mut buffer = Self::blank(size.width, size.height);
+        for point in Rectangle::new(Point::zero(), size).points() {
+            if let Some(color) = display.get_pixel(point) {
+                buffer.set_pixel(point, color);
+            }
+        }
+        buffer
+    }
+}
+
+impl<B> OriginDimensions for BinaryBuffer<B> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<B> GetPixel for BinaryBuffer<B>
+where
+    B: AsRef<[u8]>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits().get(p).into())
+    }
+}
+
+impl<B> RowSummary for BinaryBuffer<B>
+where
+    B: AsRef<[u8]>,
+{
+    fn is_row_blank(&self, y: u32) -> bool {
+        y >= self.size.height || self.bits().is_row_blank(y, self.size.width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_set_pixel() {
+        let mut buffer = BinaryBuffer::new([0u8; 4], 10, 2).unwrap();
+        buffer.set_pixel(Point::new(9, 1), BinaryColor::On);
+        buffer.set_pixel(Point::new(10, 1), BinaryColor::On);
+        assert_eq!(buffer.data(), &[0x00, 0x00, 0x00, 0x40]);
+        assert_eq!(buffer.pixel(Point::new(9, 1)), Some(BinaryColor::On));
+        assert_eq!(
+            buffer.as_raw().pixel(Point::new(9, 1)),
+            Some(BinaryColor::On)
+        );
+    }
+
+    #[test]
+    fn test_buffer_too_short() {
+        assert!(BinaryBuffer::new([0u8; 3], 10, 2).is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_buffer_from_memory() {
+        let buffer =
+            BinaryBuffer::from_memory(include_bytes!("../../testdata/checker.png"), 128).unwrap();
+        assert_eq!(buffer.size(), Size::new(4, 2));
+        assert_eq!(buffer.data(), &[0xa0, 0x50]);
+
+        let buffer =
+            BinaryBuffer::from_memory(include_bytes!("../../testdata/checker.png"), 0).unwrap();
+        assert_eq!(buffer.data(), &[0xf0, 0xf0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_buffer_from_mock_display() {
+        let display = MockDisplay::from_pattern(&["   ", "  #", " . "]);
+        let buffer = BinaryBuffer::from(&display);
+        assert_eq!(buffer.size(), Size::new(3, 3));
+        assert_eq!(buffer.data(), &[0x00, 0x20, 0x00]);
+    }
+}
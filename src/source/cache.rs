@@ -0,0 +1,95 @@
+use super::bits::{BitOrder, Bits};
+
+/// Maximum number of rows a [`RowCache`] keeps track of
+pub(crate) const MAX_ROWS: usize = 8;
+
+/// Cache for the last decoded rows of a streaming source.
+///
+/// The rows live in a caller provided buffer, with as many rows cached as
+/// fit into it, up to [`MAX_ROWS`]. Once all rows are taken, the oldest row
+/// is replaced first.
+#[derive(Debug)]
+pub(crate) struct RowCache<'b> {
+    data: &'b mut [u8],
+    width: u32,
+    rows: [Option<u32>; MAX_ROWS],
+    len: usize,
+    next: usize,
+}
+
+impl<'b> RowCache<'b> {
+    /// Number of bytes needed to cache `rows` rows of `width` pixels
+    pub(crate) const fn buffer_len(width: u32, rows: usize) -> usize {
+        width.div_ceil(8) as usize * rows
+    }
+
+    /// Returns `None` if `buffer` can't hold a single row
+    pub(crate) fn new(buffer: &'b mut [u8], width: u32) -> Option<Self> {
+        let stride = width.div_ceil(8) as usize;
+        let len = buffer
+            .len()
+            .checked_div(stride)
+            .unwrap_or(MAX_ROWS)
+            .min(MAX_ROWS);
+        if len == 0 {
+            return None;
+        }
+
+        Some(Self {
+            data: &mut buffer[..stride * len],
+            width,
+            rows: [None; MAX_ROWS],
+            len,
+            next: 0,
+        })
+    }
+
+    fn row(&self, slot: usize) -> Bits<'_> {
+        let stride = self.width.div_ceil(8) as usize;
+        let row = &self.data[slot * stride..(slot + 1) * stride];
+        Bits::new(row, self.width, BitOrder::MsbFirst)
+    }
+
+    /// Returns the cached row `y`, if present
+    pub(crate) fn get(&self, y: u32) -> Option<Bits<'_>> {
+        let slot = self.rows[..self.len]
+            .iter()
+            .position(|&row| row == Some(y))?;
+        Some(self.row(slot))
+    }
+
+    /// Evict the oldest row and return its storage
+    ///
+    /// The row is only marked as cached once [`RowCache::commit`] is called.
+    pub(crate) fn slot(&mut self) -> &mut [u8] {
+        let stride = self.width.div_ceil(8) as usize;
+        self.rows[self.next] = None;
+        &mut self.data[self.next * stride..(self.next + 1) * stride]
+    }
+
+    /// Mark the row returned by the last [`RowCache::slot`] call as row `y`
+    pub(crate) fn commit(&mut self, y: u32) {
+        self.rows[self.next] = Some(y);
+        self.next = (self.next + 1) % self.len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::prelude::Point;
+
+    #[test]
+    fn test_row_cache_evicts_oldest() {
+        let mut buffer = [0; 2];
+        let mut cache = RowCache::new(&mut buffer, 8).unwrap();
+        for y in 0..3 {
+            cache.slot()[0] = 0x80 >> y;
+            cache.commit(y);
+        }
+
+        assert!(cache.get(0).is_none());
+        assert!(cache.get(1).unwrap().get(Point::new(1, 0)));
+        assert!(cache.get(2).unwrap().get(Point::new(2, 0)));
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(feature = "std")]
+use super::BinaryBuffer;
+use embedded_canvas::Canvas;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+#[cfg(feature = "std")]
+use embedded_graphics::{prelude::PointsIter, primitives::Rectangle};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Binary [`Canvas`] as an image source.
+///
+/// Pixels that haven't been drawn on the canvas are `Off`.
+#[derive(Clone, Copy)]
+pub struct CanvasImage<'a> {
+    canvas: &'a Canvas<BinaryColor>,
+}
+
+impl<'a> CanvasImage<'a> {
+    /// Use the pixels of a canvas as image
+    pub const fn new(canvas: &'a Canvas<BinaryColor>) -> Self {
+        Self { canvas }
+    }
+}
+
+impl<'a> From<&'a Canvas<BinaryColor>> for CanvasImage<'a> {
+    fn from(canvas: &'a Canvas<BinaryColor>) -> Self {
+        Self::new(canvas)
+    }
+}
+
+impl core::fmt::Debug for CanvasImage<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CanvasImage")
+            .field("size", &self.canvas.canvas)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OriginDimensions for CanvasImage<'_> {
+    fn size(&self) -> Size {
+        self.canvas.canvas
+    }
+}
+
+impl GetPixel for CanvasImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let size = self.canvas.canvas;
+        let (x, y) = <(u32, u32)>::try_from(p).ok()?;
+        if x >= size.width || y >= size.height {
+            return None;
+        }
+
+        let index = y as usize * size.width as usize + x as usize;
+        Some(self.canvas.pixels[index].unwrap_or(BinaryColor::Off))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&Canvas<BinaryColor>> for BinaryBuffer<Vec<u8>> {
+    /// Copy the pixels of a canvas into a new image
+    ///
+    /// Pixels that haven't been drawn on the canvas are `Off`.
+    fn from(canvas: &Canvas<BinaryColor>) -> Self {
+        let source = CanvasImage::new(canvas);
+        let size = source.size();
+
+        let mut buffer = Self::blank(size.width, size.height);
+        for point in Rectangle::new(Point::zero(), size).points() {
+            if let Some(color) = source.pixel(point) {
+                buffer.set_pixel(point, color);
+            }
+        }
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{
+        Drawable,
+        pixelcolor::Rgb565,
+        prelude::{Primitive, RgbColor},
+        primitives::{Line, PrimitiveStyle},
+    };
+
+    #[test]
+    fn test_canvas_round_trip() {
+        let mut canvas = Canvas::new(Size::new(3, 2));
+        Line::new(Point::new(0, 1), Point::new(2, 1))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut canvas)
+            .unwrap();
+
+        let source = CanvasImage::new(&canvas);
+        assert_eq!(source.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(source.pixel(Point::new(3, 1)), None);
+
+        let mut target = Canvas::new(Size::new(3, 2));
+        Image::new(&source, Point::zero(), Rgb565::GREEN).draw_canvas(&mut target);
+        assert_eq!(target.pixels[3..], [Some(Rgb565::GREEN); 3]);
+        assert_eq!(target.pixels[..3], [None; 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_canvas_into_buffer() {
+        let mut canvas = Canvas::new(Size::new(10, 2));
+        Line::new(Point::new(1, 1), Point::new(9, 1))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut canvas)
+            .unwrap();
+
+        let buffer = BinaryBuffer::from(&canvas);
+        assert_eq!(buffer.size(), Size::new(10, 2));
+        assert_eq!(buffer.data(), &[0x00, 0x00, 0x7f, 0xc0]);
+    }
+}
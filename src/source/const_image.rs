@@ -0,0 +1,98 @@
+use super::{
+    RowSummary,
+    bits::{self, BitOrder, Bits},
+};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Binary image with dimensions known at compile time.
+///
+/// The data uses the same layout as [`ImageRaw<BinaryColor>`]: MSB-first with
+/// byte-aligned rows. The length of the data array is checked against the
+/// dimensions at compile time:
+///
+/// ```rust
+/// use embedded_graphics_colorcast::source::ConstImage;
+///
+/// static ICON: ConstImage<10, 2> = ConstImage::new(&[0xff, 0xc0, 0x80, 0x40]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use embedded_graphics_colorcast::source::ConstImage;
+///
+/// // A 10 pixel wide row needs 2 bytes, so this is missing a byte.
+/// static ICON: ConstImage<10, 2> = ConstImage::new(&[0xff, 0xc0, 0x80]);
+/// ```
+///
+/// [`ImageRaw<BinaryColor>`]: embedded_graphics::image::ImageRaw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstImage<'a, const W: u32, const H: u32> {
+    data: &'a [u8],
+}
+
+impl<'a, const W: u32, const H: u32> ConstImage<'a, W, H> {
+    /// Number of bytes required for an image of this size
+    pub const DATA_LEN: usize = W.div_ceil(8) as usize * H as usize;
+
+    /// Create a new image, failing to compile if `data` has the wrong length
+    pub const fn new<const N: usize>(data: &'a [u8; N]) -> Self {
+        const {
+            assert!(
+                N == Self::DATA_LEN,
+                "data length doesn't match the image dimensions"
+            )
+        };
+        Self { data }
+    }
+
+    /// Returns the packed pixel data
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    fn bits(&self) -> Bits<'a> {
+        Bits::new(self.data, W, BitOrder::MsbFirst)
+    }
+}
+
+impl<const W: u32, const H: u32> OriginDimensions for ConstImage<'_, W, H> {
+    fn size(&self) -> Size {
+        Size::new(W, H)
+    }
+}
+
+impl<const W: u32, const H: u32> GetPixel for ConstImage<'_, W, H> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size(), p).then(|| self.bits().get(p).into())
+    }
+}
+
+impl<const W: u32, const H: u32> RowSummary for ConstImage<'_, W, H> {
+    fn is_row_blank(&self, y: u32) -> bool {
+        y >= H || self.bits().is_row_blank(y, W)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_image() {
+        const IMAGE: ConstImage<10, 2> = ConstImage::new(&[0xff, 0xc0, 0x80, 0x40]);
+        assert_eq!(IMAGE.size(), Size::new(10, 2));
+        assert!(!IMAGE.is_row_blank(0));
+        // Only the padding bits are set
+        assert!(ConstImage::<2, 1>::new(&[0x3f]).is_row_blank(0));
+        assert_eq!(IMAGE.pixel(Point::new(9, 0)), Some(BinaryColor::On));
+        assert_eq!(IMAGE.pixel(Point::new(9, 1)), Some(BinaryColor::On));
+        assert_eq!(IMAGE.pixel(Point::new(8, 1)), Some(BinaryColor::Off));
+        assert_eq!(IMAGE.pixel(Point::new(10, 0)), None);
+    }
+}
@@ -0,0 +1,24 @@
+/// CRC-32 (IEEE 802.3) checksum, as used by zlib and PNG
+///
+/// The checksum is computed bit by bit to avoid a lookup table in flash.
+///
+/// ```rust
+/// use embedded_graphics_colorcast::source::crc32;
+///
+/// assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+/// ```
+pub const fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    let mut i = 0;
+    while i < data.len() {
+        crc ^= data[i] as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+            bit += 1;
+        }
+        i += 1;
+    }
+    !crc
+}
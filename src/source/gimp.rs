@@ -0,0 +1,112 @@
+use super::bits;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::{BinaryColor, Rgb565, Rgb888, raw::RawU16},
+    prelude::{Point, Size},
+};
+
+/// Image exported by GIMP as "C source".
+///
+/// GIMP writes these as a struct with `width`, `height`, `bytes_per_pixel`
+/// and `pixel_data` fields. Black and white images are exported as RGB
+/// data, so every pixel is converted back to [`BinaryColor`] using the same
+/// luma threshold as the `embedded-graphics` color conversions: light pixels
+/// are `On`, dark pixels are `Off`. Fully transparent RGBA pixels are `Off`.
+///
+/// ```rust
+/// use embedded_graphics_colorcast::source::GimpImage;
+///
+/// // static const struct { ... } gimp_image = { 2, 1, 3, "\377\377\377\000\000\000" };
+/// let image = GimpImage::new(2, 1, 3, b"\xff\xff\xff\x00\x00\x00\0").unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GimpImage<'a> {
+    data: &'a [u8],
+    size: Size,
+    bytes_per_pixel: usize,
+}
+
+impl<'a> GimpImage<'a> {
+    /// Create a new image from the fields of a GIMP C source struct
+    ///
+    /// Returns `None` if `bytes_per_pixel` isn't one of the values GIMP
+    /// exports (2 for RGB16, 3 for RGB, 4 for RGBA). If `pixel_data` holds
+    /// fewer than `height` rows, the height is reduced to the number of
+    /// complete rows. The trailing NUL byte of the C string is ignored.
+    pub const fn new(
+        width: u32,
+        height: u32,
+        bytes_per_pixel: u32,
+        pixel_data: &'a [u8],
+    ) -> Option<Self> {
+        if !matches!(bytes_per_pixel, 2..=4) {
+            return None;
+        }
+
+        let stride = width as usize * bytes_per_pixel as usize;
+        let rows = match pixel_data.len().checked_div(stride) {
+            Some(rows) => rows as u32,
+            None => 0,
+        };
+        let height = if height < rows { height } else { rows };
+
+        Some(Self {
+            data: pixel_data,
+            size: Size::new(width, height),
+            bytes_per_pixel: bytes_per_pixel as usize,
+        })
+    }
+}
+
+impl OriginDimensions for GimpImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for GimpImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let index = (p.y as usize * self.size.width as usize + p.x as usize) * self.bytes_per_pixel;
+        let pixel = self.data.get(index..index + self.bytes_per_pixel)?;
+
+        Some(match *pixel {
+            [lo, hi] => Rgb565::from(RawU16::new(u16::from_le_bytes([lo, hi]))).into(),
+            [r, g, b] => Rgb888::new(r, g, b).into(),
+            [_, _, _, 0] => BinaryColor::Off,
+            [r, g, b, _] => Rgb888::new(r, g, b).into(),
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gimp_rgb() {
+        let image = GimpImage::new(2, 1, 3, b"\xff\xff\xff\x00\x00\x00\0").unwrap();
+        assert_eq!(image.size(), Size::new(2, 1));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn test_gimp_rgba_transparent_is_off() {
+        let image = GimpImage::new(1, 2, 4, b"\xff\xff\xff\x00\xff\xff\xff\xff").unwrap();
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(0, 1)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn test_gimp_invalid_bytes_per_pixel() {
+        assert!(GimpImage::new(1, 1, 1, b"\xff").is_none());
+    }
+}
@@ -0,0 +1,228 @@
+use super::{
+    bits::{self, Bits},
+    cache::RowCache,
+};
+use core::cell::RefCell;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Heatshrink (LZSS) compressed binary image.
+///
+/// The decompressed data uses the same layout as
+/// [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw): MSB-first
+/// with byte-aligned rows. Decompression happens row by row while the image
+/// is drawn, using a caller provided buffer that holds the LZSS window and
+/// the last decoded rows, so the image is never expanded in RAM. A buffer
+/// of [`HeatshrinkImage::cached_buffer_len`] bytes caches more than one row,
+/// which helps when the draw loop revisits rows, like under rotating views.
+///
+/// Rows have to be decoded in order. Reading a row before the current one
+/// that isn't cached anymore restarts decompression at the beginning of the
+/// data.
+#[derive(Debug)]
+pub struct HeatshrinkImage<'a, 'b> {
+    size: Size,
+    state: RefCell<State<'a, 'b>>,
+}
+
+impl<'a, 'b> HeatshrinkImage<'a, 'b> {
+    /// Size of the buffer required by [`HeatshrinkImage::new`]
+    pub const fn buffer_len(width: u32, window_sz2: u8) -> usize {
+        Self::cached_buffer_len(width, window_sz2, 1)
+    }
+
+    /// Size of a buffer that caches `rows` decoded rows
+    ///
+    /// At most 8 rows are cached, extra space in the buffer is unused.
+    pub const fn cached_buffer_len(width: u32, window_sz2: u8, rows: usize) -> usize {
+        (1 << window_sz2) + RowCache::buffer_len(width, rows)
+    }
+
+    /// Create a new image from heatshrink compressed data
+    ///
+    /// `window_sz2` and `lookahead_sz2` must match the parameters used by the
+    /// encoder. Returns `None` if the parameters are invalid or `buffer` is
+    /// shorter than [`HeatshrinkImage::buffer_len`].
+    pub fn new(
+        data: &'a [u8],
+        width: u32,
+        height: u32,
+        window_sz2: u8,
+        lookahead_sz2: u8,
+        buffer: &'b mut [u8],
+    ) -> Option<Self> {
+        if !(4..=15).contains(&window_sz2) || !(3..window_sz2).contains(&lookahead_sz2) {
+            return None;
+        }
+
+        let window_len = 1 << window_sz2;
+        let (window, rows) = buffer.split_at_mut_checked(window_len)?;
+
+        Some(Self {
+            size: Size::new(width, height),
+            state: RefCell::new(State {
+                decoder: Decoder {
+                    input: data,
+                    window_sz2,
+                    lookahead_sz2,
+                    window,
+                    y: None,
+                    bit: 0,
+                    head: 0,
+                    backref: None,
+                },
+                cache: RowCache::new(rows, width)?,
+            }),
+        })
+    }
+}
+
+impl OriginDimensions for HeatshrinkImage<'_, '_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for HeatshrinkImage<'_, '_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let row = state.seek(p.y as u32)?;
+        Some(row.get(Point::new(p.x, 0)).into())
+    }
+}
+
+#[derive(Debug)]
+struct State<'a, 'b> {
+    decoder: Decoder<'a, 'b>,
+    cache: RowCache<'b>,
+}
+
+impl State<'_, '_> {
+    fn seek(&mut self, y: u32) -> Option<Bits<'_>> {
+        if self.cache.get(y).is_none() {
+            let decoder = &mut self.decoder;
+            if decoder.y.is_some_and(|current| y < current) {
+                decoder.reset();
+            }
+
+            while decoder.y != Some(y) {
+                for byte in self.cache.slot() {
+                    *byte = decoder.next_byte().unwrap_or(0);
+                }
+                let next = decoder.y.map_or(0, |current| current + 1);
+                self.cache.commit(next);
+                decoder.y = Some(next);
+            }
+        }
+
+        self.cache.get(y)
+    }
+}
+
+#[derive(Debug)]
+struct Decoder<'a, 'b> {
+    input: &'a [u8],
+    window_sz2: u8,
+    lookahead_sz2: u8,
+    window: &'b mut [u8],
+    /// Index of the last decoded row
+    y: Option<u32>,
+    /// Read position in `input`, in bits
+    bit: usize,
+    /// Write position in `window`
+    head: usize,
+    /// Pending back reference as `(offset, remaining)`
+    backref: Option<(usize, u16)>,
+}
+
+impl Decoder<'_, '_> {
+    fn reset(&mut self) {
+        self.y = None;
+        self.bit = 0;
+        self.head = 0;
+        self.backref = None;
+        self.window.fill(0);
+    }
+
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = if let Some((offset, remaining)) = self.backref {
+            self.backref = (remaining > 1).then_some((offset, remaining - 1));
+            self.lookup(offset)
+        } else if self.read_bits(1)? == 1 {
+            self.read_bits(8)? as u8
+        } else {
+            let offset = usize::from(self.read_bits(self.window_sz2)?) + 1;
+            let count = self.read_bits(self.lookahead_sz2)? + 1;
+            self.backref = (count > 1).then_some((offset, count - 1));
+            self.lookup(offset)
+        };
+
+        let mask = self.window.len() - 1;
+        self.window[self.head & mask] = byte;
+        self.head = self.head.wrapping_add(1);
+        Some(byte)
+    }
+
+    fn lookup(&self, offset: usize) -> u8 {
+        let mask = self.window.len() - 1;
+        self.window[self.head.wrapping_sub(offset) & mask]
+    }
+
+    fn read_bits(&mut self, count: u8) -> Option<u16> {
+        let mut value = 0;
+        for _ in 0..count {
+            let byte = self.input.get(self.bit / 8)?;
+            let bit = (byte >> (7 - self.bit % 8)) & 1;
+            value = (value << 1) | u16::from(bit);
+            self.bit += 1;
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heatshrink_literals_and_backref() {
+        // Encoded with window_sz2 = 8, lookahead_sz2 = 4 from [0xf0, 0xf0, 0xf0, 0x0f]:
+        // literal 0xf0, backref (offset 1, count 2), literal 0x0f.
+        let data = [0xf8, 0x00, 0x06, 0x1e];
+        let mut buffer = [0; HeatshrinkImage::buffer_len(8, 8)];
+        let image = HeatshrinkImage::new(&data, 8, 4, 8, 4, &mut buffer).unwrap();
+
+        for y in 0..3 {
+            assert_eq!(image.pixel(Point::new(0, y)), Some(BinaryColor::On));
+            assert_eq!(image.pixel(Point::new(4, y)), Some(BinaryColor::Off));
+        }
+        assert_eq!(image.pixel(Point::new(0, 3)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(7, 3)), Some(BinaryColor::On));
+
+        // Going backwards restarts decompression.
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+
+        // With two cached rows, revisiting the previous row doesn't.
+        let mut buffer = [0; HeatshrinkImage::cached_buffer_len(8, 8, 2)];
+        let image = HeatshrinkImage::new(&data, 8, 4, 8, 4, &mut buffer).unwrap();
+        assert_eq!(image.pixel(Point::new(7, 3)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(0, 2)), Some(BinaryColor::On));
+        assert_eq!(image.state.borrow().decoder.y, Some(3));
+    }
+
+    #[test]
+    fn test_heatshrink_buffer_too_small() {
+        let mut buffer = [0; 16];
+        assert!(HeatshrinkImage::new(&[], 8, 1, 8, 4, &mut buffer).is_none());
+    }
+}
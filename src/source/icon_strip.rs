@@ -0,0 +1,74 @@
+use crate::view::Cropped;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+/// Icon font stored as a single strip of fixed-width glyphs.
+///
+/// The glyphs are placed next to each other in one wide binary image and
+/// addressed by their index, starting at the left edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IconStrip<'a, T> {
+    image: &'a T,
+    glyph_width: u32,
+}
+
+impl<'a, T> IconStrip<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Create a new strip with glyphs of the given width
+    pub const fn new(image: &'a T, glyph_width: u32) -> Self {
+        Self { image, glyph_width }
+    }
+
+    /// Returns the number of glyphs in the strip
+    pub fn len(&self) -> usize {
+        self.image
+            .size()
+            .width
+            .checked_div(self.glyph_width)
+            .unwrap_or(0) as usize
+    }
+
+    /// Returns `true` if the strip doesn't contain any glyphs
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size of a single glyph
+    pub fn glyph_size(&self) -> Size {
+        Size::new(self.glyph_width, self.image.size().height)
+    }
+
+    /// Returns the glyph at `index`, or `None` if it's out of range
+    pub fn glyph(&self, index: usize) -> Option<Cropped<'a, T>> {
+        let x = (index < self.len()).then(|| index as u32 * self.glyph_width)?;
+        let area = Rectangle::new(Point::new(x as i32, 0), self.glyph_size());
+        Some(Cropped::new(self.image, &area))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_icon_strip_glyphs() {
+        let image = AsciiImage::from_rows(&["#...#..", ".#..##."]).unwrap();
+        let strip = IconStrip::new(&image, 3);
+        assert_eq!(strip.len(), 2);
+
+        let glyph = strip.glyph(1).unwrap();
+        assert_eq!(glyph.size(), Size::new(3, 2));
+        assert_eq!(glyph.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(glyph.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(glyph.pixel(Point::new(2, 1)), Some(BinaryColor::On));
+        assert!(strip.glyph(2).is_none());
+    }
+}
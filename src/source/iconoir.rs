@@ -0,0 +1,80 @@
+use super::BinaryBuffer;
+use crate::CaptureTarget;
+use embedded_graphics::{
+    geometry::OriginDimensions, image::ImageDrawable, pixelcolor::BinaryColor,
+};
+use embedded_iconoir::{Icon, prelude::IconoirIcon};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+impl<B> BinaryBuffer<B>
+where
+    B: AsRef<[u8]> + AsMut<[u8]>,
+{
+    /// Render an [`embedded-iconoir`](embedded_iconoir) icon into `data`
+    ///
+    /// The icon needs to be created with `BinaryColor::On`. Returns `None` if
+    /// `data` is shorter than [`BinaryBuffer::data_len`] for the icon size.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+    /// use embedded_graphics_colorcast::{Image, source::BinaryBuffer};
+    /// use embedded_iconoir::prelude::*;
+    ///
+    /// let icon = icons::size24px::actions::Download::new(BinaryColor::On);
+    /// let buffer = BinaryBuffer::from_iconoir([0; 72], &icon).unwrap();
+    /// let image = Image::new(&buffer, Point::zero(), Rgb565::CSS_TEAL);
+    /// ```
+    pub fn from_iconoir<T: IconoirIcon>(mut data: B, icon: &Icon<BinaryColor, T>) -> Option<Self> {
+        data.as_mut().fill(0);
+        let size = icon.size();
+        let mut capture = CaptureTarget::new(Self::new(data, size.width, size.height)?);
+        let Ok(()) = icon.draw(&mut capture);
+        Some(capture.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: IconoirIcon> From<&Icon<BinaryColor, T>> for BinaryBuffer<Vec<u8>> {
+    fn from(icon: &Icon<BinaryColor, T>) -> Self {
+        let size = icon.size();
+        let mut capture = CaptureTarget::new(Self::blank(size.width, size.height));
+        let Ok(()) = icon.draw(&mut capture);
+        capture.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{
+        Drawable, image,
+        mock_display::MockDisplay,
+        pixelcolor::{Rgb565, WebColors},
+        prelude::Point,
+    };
+    use embedded_iconoir::prelude::*;
+
+    #[test]
+    fn test_from_iconoir() {
+        let icon = icons::size24px::actions::Download::new(BinaryColor::On);
+        let buffer = BinaryBuffer::from_iconoir([0; 72], &icon).unwrap();
+
+        let mut expected = MockDisplay::new();
+        image::Image::new(
+            &icons::size24px::actions::Download::new(Rgb565::CSS_TEAL),
+            Point::zero(),
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&buffer, Point::zero(), Rgb565::CSS_TEAL)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_eq(&expected);
+
+        assert!(BinaryBuffer::from_iconoir([0; 71], &icon).is_none());
+    }
+}
@@ -0,0 +1,58 @@
+//! Binary image sources for common 1bpp data layouts.
+//!
+//! Every type in this module implements [`GetPixel<Color = BinaryColor>`] and
+//! [`OriginDimensions`], so it can be passed to [`Image::new`](crate::Image::new).
+//!
+//! [`GetPixel<Color = BinaryColor>`]: embedded_graphics::image::GetPixel
+//! [`OriginDimensions`]: embedded_graphics::geometry::OriginDimensions
+
+mod adafruit;
+pub(crate) mod ascii;
+mod bdf;
+mod bits;
+mod buffer;
+mod cache;
+#[cfg(feature = "canvas")]
+mod canvas;
+mod const_image;
+mod crc;
+mod gimp;
+mod heatshrink;
+mod icon_strip;
+#[cfg(feature = "iconoir")]
+mod iconoir;
+pub mod pack;
+mod paged;
+#[cfg(feature = "qrcodegen")]
+mod qr;
+mod raw;
+mod reader;
+pub mod rle;
+#[cfg(feature = "embedded-sdmmc")]
+mod sdmmc;
+mod storage;
+mod summary;
+mod u8g2;
+
+pub use self::adafruit::AdafruitBitmap;
+pub use self::ascii::AsciiImage;
+pub use self::bdf::{BdfFont, BdfGlyph};
+pub use self::bits::BitOrder;
+pub use self::buffer::BinaryBuffer;
+#[cfg(feature = "canvas")]
+pub use self::canvas::CanvasImage;
+pub use self::const_image::ConstImage;
+pub use self::crc::crc32;
+pub use self::gimp::GimpImage;
+pub use self::heatshrink::HeatshrinkImage;
+pub use self::icon_strip::IconStrip;
+pub use self::pack::{Asset, AssetPack};
+pub use self::paged::PagedImage;
+#[cfg(feature = "qrcodegen")]
+pub use self::qr::QrSource;
+pub use self::raw::{ColumnMajorImage, RawImage};
+pub use self::reader::PixelDataReader;
+pub use self::rle::RleImage;
+pub use self::storage::{ReadStorage, StorageError, StorageImage};
+pub use self::summary::RowSummary;
+pub use self::u8g2::{U8g2Bitmap, Xbm};
@@ -0,0 +1,368 @@
+//! Asset packs bundling many 1bpp images in a single blob.
+//!
+//! The container starts with a 4 byte [`MAGIC`], followed by the number of
+//! assets as little endian `u16` and a `u16` of flags. Then comes one
+//! [`ENTRY_LEN`] byte index entry per asset:
+//!
+//! | bytes | content                                     |
+//! |-------|---------------------------------------------|
+//! | 4     | [`name_hash`] of the asset name             |
+//! | 4     | offset of the data from the start of the blob |
+//! | 4     | length of the data                          |
+//! | 2     | width                                       |
+//! | 2     | height                                      |
+//! | 1     | format, `0` for raw and `1` for RLE         |
+//! | 3     | reserved                                    |
+//!
+//! If [`FLAG_CRC`] is set, the index is followed by the [`crc32`] of every
+//! asset's data as `u32`, in index order.
+//!
+//! Raw data uses the [`BinaryBuffer`] layout, RLE data is a complete
+//! [`rle`](super::rle) container. All numbers are little endian.
+
+use super::{BinaryBuffer, RleImage, crc32};
+use core::fmt;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Magic bytes at the start of every asset pack
+pub const MAGIC: [u8; 4] = *b"PAK1";
+
+/// Length of the pack header in bytes
+pub const HEADER_LEN: usize = MAGIC.len() + 4;
+
+/// Length of a single index entry in bytes
+pub const ENTRY_LEN: usize = 20;
+
+/// Header flag for packs that store a checksum of every asset
+pub const FLAG_CRC: u16 = 0x0001;
+
+pub(crate) const FORMAT_RAW: u8 = 0;
+pub(crate) const FORMAT_RLE: u8 = 1;
+
+/// Errors returned when reading or writing asset packs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The data doesn't start with [`MAGIC`]
+    BadMagic,
+    /// The index or an asset points past the end of the data
+    Truncated,
+    /// An asset uses an unknown format
+    BadFormat,
+    /// An RLE asset is invalid or doesn't match its index entry
+    Rle(super::rle::Error),
+    /// The pack is too large for the container format
+    TooLarge,
+    /// The data of an asset doesn't match its stored [`crc32`]
+    Checksum,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::BadMagic => f.write_str("missing asset pack magic bytes"),
+            Error::Truncated => f.write_str("asset pack is truncated"),
+            Error::BadFormat => f.write_str("unknown asset format"),
+            Error::Rle(err) => write!(f, "invalid RLE asset: {err}"),
+            Error::TooLarge => f.write_str("asset pack is too large"),
+            Error::Checksum => f.write_str("asset checksum mismatch"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<super::rle::Error> for Error {
+    fn from(err: super::rle::Error) -> Self {
+        Self::Rle(err)
+    }
+}
+
+/// FNV-1a hash used to look up assets by name
+pub const fn name_hash(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut hash = 0x811c_9dc5_u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+        i += 1;
+    }
+    hash
+}
+
+/// Index entry describing a single asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Entry {
+    pub(crate) hash: u32,
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) size: Size,
+    pub(crate) format: u8,
+}
+
+impl Entry {
+    fn parse(raw: &[u8]) -> Self {
+        let u32_at = |i: usize| u32::from_le_bytes([raw[i], raw[i + 1], raw[i + 2], raw[i + 3]]);
+        let u16_at = |i: usize| u16::from_le_bytes([raw[i], raw[i + 1]]);
+        Self {
+            hash: u32_at(0),
+            offset: u32_at(4) as usize,
+            len: u32_at(8) as usize,
+            size: Size::new(u16_at(12).into(), u16_at(14).into()),
+            format: raw[16],
+        }
+    }
+
+    /// Serialize the entry, failing if a field doesn't fit the format
+    #[cfg(feature = "std")]
+    pub(crate) fn to_bytes(self) -> Result<[u8; ENTRY_LEN], Error> {
+        let offset = u32::try_from(self.offset).map_err(|_| Error::TooLarge)?;
+        let len = u32::try_from(self.len).map_err(|_| Error::TooLarge)?;
+        let width = u16::try_from(self.size.width).map_err(|_| Error::TooLarge)?;
+        let height = u16::try_from(self.size.height).map_err(|_| Error::TooLarge)?;
+
+        let mut out = [0; ENTRY_LEN];
+        out[0..4].copy_from_slice(&self.hash.to_le_bytes());
+        out[4..8].copy_from_slice(&offset.to_le_bytes());
+        out[8..12].copy_from_slice(&len.to_le_bytes());
+        out[12..14].copy_from_slice(&width.to_le_bytes());
+        out[14..16].copy_from_slice(&height.to_le_bytes());
+        out[16] = self.format;
+        Ok(out)
+    }
+}
+
+/// Zero-copy reader for an asset pack.
+///
+/// The index and all assets are checked once, so looking up assets
+/// afterwards can't fail.
+///
+/// ```rust
+/// use embedded_graphics::prelude::*;
+/// use embedded_graphics_colorcast::source::pack::{AssetPack, name_hash};
+///
+/// let hash = name_hash("dot").to_le_bytes();
+/// let data = [
+///     b'P', b'A', b'K', b'1', 1, 0, 0, 0,
+///     hash[0], hash[1], hash[2], hash[3], 28, 0, 0, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0,
+///     0x80,
+/// ];
+///
+/// let pack = AssetPack::new(&data).unwrap();
+/// let dot = pack.find("dot").unwrap();
+/// assert_eq!(dot.size(), Size::new(1, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetPack<'a> {
+    data: &'a [u8],
+    count: usize,
+}
+
+impl<'a> AssetPack<'a> {
+    /// Parse an asset pack
+    ///
+    /// If the pack has [`FLAG_CRC`] set, the data of every asset is checked
+    /// against its stored checksum.
+    pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        let header = data.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(Error::BadMagic);
+        }
+
+        let count = u16::from_le_bytes([header[4], header[5]]).into();
+        let flags = u16::from_le_bytes([header[6], header[7]]);
+        let index_end = HEADER_LEN + count * ENTRY_LEN;
+        if data.len() < index_end {
+            return Err(Error::Truncated);
+        }
+
+        let checksums = if flags & FLAG_CRC != 0 {
+            Some(
+                data.get(index_end..index_end + count * 4)
+                    .ok_or(Error::Truncated)?,
+            )
+        } else {
+            None
+        };
+
+        let pack = Self { data, count };
+        for index in 0..count {
+            let entry = pack.entry(index);
+            pack.load(entry)?;
+            if let Some(checksums) = checksums {
+                let stored = &checksums[index * 4..index * 4 + 4];
+                let stored = u32::from_le_bytes([stored[0], stored[1], stored[2], stored[3]]);
+                if crc32(&data[entry.offset..entry.offset + entry.len]) != stored {
+                    return Err(Error::Checksum);
+                }
+            }
+        }
+        Ok(pack)
+    }
+
+    /// Returns the number of assets in the pack
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns `true` if the pack doesn't contain any assets
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the asset at `index`
+    pub fn get(&self, index: usize) -> Option<Asset<'a>> {
+        (index < self.count).then(|| self.load(self.entry(index)).ok())?
+    }
+
+    /// Returns the first asset with the given name
+    pub fn find(&self, name: &str) -> Option<Asset<'a>> {
+        self.find_hash(name_hash(name))
+    }
+
+    /// Returns the first asset with the given [`name_hash`]
+    pub fn find_hash(&self, hash: u32) -> Option<Asset<'a>> {
+        (0..self.count)
+            .map(|index| self.entry(index))
+            .find(|entry| entry.hash == hash)
+            .and_then(|entry| self.load(entry).ok())
+    }
+
+    /// Returns an iterator over all assets and their name hashes
+    pub fn iter(&self) -> impl Iterator<Item = (u32, Asset<'a>)> + '_ {
+        (0..self.count).filter_map(|index| {
+            let entry = self.entry(index);
+            Some((entry.hash, self.load(entry).ok()?))
+        })
+    }
+
+    fn entry(&self, index: usize) -> Entry {
+        let start = HEADER_LEN + index * ENTRY_LEN;
+        Entry::parse(&self.data[start..start + ENTRY_LEN])
+    }
+
+    fn load(&self, entry: Entry) -> Result<Asset<'a>, Error> {
+        let data = entry
+            .offset
+            .checked_add(entry.len)
+            .and_then(|end| self.data.get(entry.offset..end))
+            .ok_or(Error::Truncated)?;
+
+        match entry.format {
+            FORMAT_RAW => BinaryBuffer::new(data, entry.size.width, entry.size.height)
+                .map(Asset::Raw)
+                .ok_or(Error::Truncated),
+            FORMAT_RLE => {
+                let image = RleImage::new(data)?;
+                if image.size() != entry.size {
+                    return Err(Error::Rle(super::rle::Error::Truncated));
+                }
+                Ok(Asset::Rle(image))
+            }
+            _ => Err(Error::BadFormat),
+        }
+    }
+}
+
+/// Single image stored in an [`AssetPack`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Asset<'a> {
+    /// Uncompressed pixel data
+    Raw(BinaryBuffer<&'a [u8]>),
+    /// Run-length encoded pixel data
+    Rle(RleImage<'a>),
+}
+
+impl OriginDimensions for Asset<'_> {
+    fn size(&self) -> Size {
+        match self {
+            Self::Raw(image) => image.size(),
+            Self::Rle(image) => image.size(),
+        }
+    }
+}
+
+impl GetPixel for Asset<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        match self {
+            Self::Raw(image) => image.pixel(p),
+            Self::Rle(image) => image.pixel(p),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        name: &str,
+        offset: u32,
+        len: u32,
+        width: u16,
+        height: u16,
+        format: u8,
+    ) -> [u8; ENTRY_LEN] {
+        let mut out = [0; ENTRY_LEN];
+        out[0..4].copy_from_slice(&name_hash(name).to_le_bytes());
+        out[4..8].copy_from_slice(&offset.to_le_bytes());
+        out[8..12].copy_from_slice(&len.to_le_bytes());
+        out[12..14].copy_from_slice(&width.to_le_bytes());
+        out[14..16].copy_from_slice(&height.to_le_bytes());
+        out[16] = format;
+        out
+    }
+
+    #[test]
+    fn test_pack_lookup() {
+        let mut data = [0u8; 8 + 2 * ENTRY_LEN + 2 + 9];
+        data[..8].copy_from_slice(b"PAK1\x02\x00\x00\x00");
+        data[8..28].copy_from_slice(&entry("raw", 48, 2, 2, 2, FORMAT_RAW));
+        data[28..48].copy_from_slice(&entry("rle", 50, 9, 3, 1, FORMAT_RLE));
+        data[48..50].copy_from_slice(&[0x40, 0x80]);
+        data[50..].copy_from_slice(b"RLE1\x03\x00\x01\x00\x82");
+
+        let pack = AssetPack::new(&data).unwrap();
+        assert_eq!(pack.len(), 2);
+        let raw = pack.find("raw").unwrap();
+        assert_eq!(raw.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(raw.pixel(Point::new(1, 1)), Some(BinaryColor::Off));
+        let rle = pack.get(1).unwrap();
+        assert_eq!(rle.size(), Size::new(3, 1));
+        assert_eq!(rle.pixel(Point::new(2, 0)), Some(BinaryColor::On));
+        assert!(pack.find("missing").is_none());
+    }
+
+    #[test]
+    fn test_pack_errors() {
+        assert_eq!(
+            AssetPack::new(b"PAK0\x00\x00\x00\x00"),
+            Err(Error::BadMagic)
+        );
+        assert_eq!(
+            AssetPack::new(b"PAK1\x01\x00\x00\x00"),
+            Err(Error::Truncated)
+        );
+
+        let mut data = [0u8; 8 + ENTRY_LEN];
+        data[..8].copy_from_slice(b"PAK1\x01\x00\x00\x00");
+        data[8..].copy_from_slice(&entry("bad", 0, 0, 0, 0, 7));
+        assert_eq!(AssetPack::new(&data), Err(Error::BadFormat));
+
+        let mut data = [0u8; 8 + ENTRY_LEN + 4 + 1];
+        data[..8].copy_from_slice(b"PAK1\x01\x00\x01\x00");
+        data[8..28].copy_from_slice(&entry("dot", 32, 1, 1, 1, FORMAT_RAW));
+        data[28..32].copy_from_slice(&crc32(&[0x80]).to_le_bytes());
+        data[32] = 0x80;
+        assert!(AssetPack::new(&data).is_ok());
+        data[32] = 0x00;
+        assert_eq!(AssetPack::new(&data), Err(Error::Checksum));
+    }
+}
@@ -0,0 +1,101 @@
+use super::bits::{self, BitOrder};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// 1bpp image stored in vertical bytes, like the framebuffer of an SSD1306.
+///
+/// Every byte holds a column of eight pixels, and a row of bytes across the
+/// whole width forms a page. Framebuffers dumped from existing monochrome
+/// firmware can be drawn without transposing them first. By default the
+/// topmost pixel of a byte is stored in the least significant bit.
+///
+/// ```rust
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::source::PagedImage;
+///
+/// // Two columns, the left one has its top pixel set
+/// let image = PagedImage::new(&[0b0000_0001, 0b1000_0000], 2, 8);
+/// assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+/// assert_eq!(image.pixel(Point::new(1, 7)), Some(BinaryColor::On));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PagedImage<'a> {
+    data: &'a [u8],
+    size: Size,
+    order: BitOrder,
+}
+
+impl<'a> PagedImage<'a> {
+    /// Create a new image from pages of `width` bytes
+    ///
+    /// If `data` holds fewer pages than needed for `height` rows, the height
+    /// is reduced to the rows of the complete pages.
+    pub const fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        let rows = match data.len().checked_div(width as usize) {
+            Some(pages) => pages as u32 * 8,
+            None => 0,
+        };
+        let height = if height < rows { height } else { rows };
+
+        Self {
+            data,
+            size: Size::new(width, height),
+            order: BitOrder::LsbFirst,
+        }
+    }
+
+    /// Read the pixels of every byte in the given order
+    ///
+    /// [`BitOrder::MsbFirst`] stores the topmost pixel in the most
+    /// significant bit.
+    pub const fn with_bit_order(mut self, order: BitOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+impl OriginDimensions for PagedImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for PagedImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let index = (p.y as usize / 8) * self.size.width as usize + p.x as usize;
+        let bit = p.y as u32 % 8;
+        let mask = match self.order {
+            BitOrder::MsbFirst => 0x80 >> bit,
+            BitOrder::LsbFirst => 0x01 << bit,
+        };
+        Some((self.data[index] & mask != 0).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paged_image() {
+        let data = [0x00, 0x00, 0b0000_0010, 0x00];
+        let image = PagedImage::new(&data, 2, 12);
+        assert_eq!(image.size(), Size::new(2, 12));
+        assert_eq!(image.pixel(Point::new(0, 9)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(0, 8)), Some(BinaryColor::Off));
+
+        let image = image.with_bit_order(BitOrder::MsbFirst);
+        assert_eq!(image.pixel(Point::new(0, 14)), None);
+        assert_eq!(PagedImage::new(&data[..3], 2, 12).size(), Size::new(2, 8));
+    }
+}
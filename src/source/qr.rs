@@ -0,0 +1,116 @@
+use super::bits;
+use core::fmt;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+use qrcodegen::QrCode;
+
+/// Module matrix of a [`QrCode`] as a binary image.
+///
+/// Dark modules are `On` and light modules, including the quiet zone, are
+/// `Off`. Every module is drawn as a square of `scale` pixels. The size
+/// saturates at `u32::MAX` for very large quiet zones or scales.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Image, source::QrSource};
+/// use qrcodegen::{QrCode, QrCodeEcc};
+///
+/// let code = QrCode::encode_text("https://example.com", QrCodeEcc::Medium).unwrap();
+/// let qr = QrSource::new(&code).with_scale(2);
+/// let image = Image::new(&qr, Point::zero(), Rgb565::CSS_NAVY).with_background(Rgb565::WHITE);
+/// ```
+#[derive(Clone, Copy)]
+pub struct QrSource<'a> {
+    code: &'a QrCode,
+    quiet_zone: u32,
+    scale: u32,
+}
+
+impl<'a> QrSource<'a> {
+    /// Create a new source with the standard quiet zone of 4 modules
+    pub const fn new(code: &'a QrCode) -> Self {
+        Self {
+            code,
+            quiet_zone: 4,
+            scale: 1,
+        }
+    }
+
+    /// Use a quiet zone of the given number of modules
+    pub const fn with_quiet_zone(mut self, modules: u32) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    /// Draw every module as a square of `scale` pixels
+    pub const fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = if scale == 0 { 1 } else { scale };
+        self
+    }
+}
+
+impl fmt::Debug for QrSource<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QrSource")
+            .field("version", &self.code.version().value())
+            .field("quiet_zone", &self.quiet_zone)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+impl OriginDimensions for QrSource<'_> {
+    fn size(&self) -> Size {
+        let modules = (self.code.size() as u32).saturating_add(self.quiet_zone.saturating_mul(2));
+        Size::new_equal(modules.saturating_mul(self.scale))
+    }
+}
+
+impl GetPixel for QrSource<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size(), p) {
+            return None;
+        }
+
+        // `contains` rules out negative coordinates, so they fit into `u32`.
+        let module = |v: i32| {
+            let module = i64::from(v as u32 / self.scale) - i64::from(self.quiet_zone);
+            i32::try_from(module).unwrap_or(i32::MIN)
+        };
+        // `get_module` returns `false` for the quiet zone outside the code.
+        Some(self.code.get_module(module(p.x), module(p.y)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qrcodegen::QrCodeEcc;
+
+    #[test]
+    fn test_qr_quiet_zone_and_scale() {
+        let code = QrCode::encode_text("colorcast", QrCodeEcc::Low).unwrap();
+        let qr = QrSource::new(&code).with_quiet_zone(1).with_scale(3);
+        assert_eq!(qr.size(), Size::new_equal((code.size() as u32 + 2) * 3));
+
+        // Quiet zone
+        assert_eq!(qr.pixel(Point::new(2, 2)), Some(BinaryColor::Off));
+        // Top left corner of the finder pattern
+        assert_eq!(qr.pixel(Point::new(3, 3)), Some(BinaryColor::On));
+        assert_eq!(qr.pixel(Point::new(5, 5)), Some(BinaryColor::On));
+
+        let huge = QrSource::new(&code)
+            .with_quiet_zone(u32::MAX)
+            .with_scale(u32::MAX);
+        assert_eq!(huge.size(), Size::new_equal(u32::MAX));
+        assert_eq!(huge.pixel(Point::new(i32::MAX, 0)), Some(BinaryColor::Off));
+        let scaled = QrSource::new(&code).with_quiet_zone(0).with_scale(u32::MAX);
+        assert_eq!(scaled.pixel(Point::new(i32::MAX, 0)), Some(BinaryColor::On));
+    }
+}
@@ -0,0 +1,222 @@
+use super::{
+    RowSummary,
+    bits::{self, BitOrder, Bits},
+};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Row-major 1bpp image with a configurable bit order.
+///
+/// This is similar to [`ImageRaw<BinaryColor>`], but can also read data
+/// that stores the leftmost pixel of a byte in the least significant bit,
+/// like XBM files and several LCD vendor tools. Such data would otherwise
+/// render mirrored within every byte.
+///
+/// ```rust
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::source::{BitOrder, RawImage};
+///
+/// let image = RawImage::new(&[0b0000_0001], 8, 1).with_bit_order(BitOrder::LsbFirst);
+/// assert_eq!(image.pixel(Point::zero()), Some(BinaryColor::On));
+/// ```
+///
+/// [`ImageRaw<BinaryColor>`]: embedded_graphics::image::ImageRaw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawImage<'a> {
+    bits: Bits<'a>,
+    size: Size,
+}
+
+impl<'a> RawImage<'a> {
+    /// Create a new MSB-first image with rows padded to a full byte
+    ///
+    /// If `data` holds fewer than `height` rows, the height is reduced to
+    /// the number of complete rows.
+    pub const fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        let bits = Bits::new(data, width, BitOrder::MsbFirst);
+        Self {
+            bits,
+            size: bits.clamp(Size::new(width, height)),
+        }
+    }
+
+    /// Create a new MSB-first image with `stride` bytes from one row to the next
+    ///
+    /// This allows using images with extra padding at the end of every row,
+    /// or images cropped from a larger buffer, without repacking them. The
+    /// last row only needs enough bytes for `width` pixels. If `data` holds
+    /// fewer than `height` rows, the height is reduced to the number of
+    /// complete rows.
+    ///
+    /// Returns `None` if `stride` is too small for `width` pixels.
+    ///
+    /// ```rust
+    /// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+    /// use embedded_graphics_colorcast::source::RawImage;
+    ///
+    /// // The right half of a 16x2 buffer
+    /// let buffer = [0x00, 0xff, 0x00, 0x01];
+    /// let image = RawImage::with_stride(&buffer[1..], 8, 2, 2).unwrap();
+    /// assert_eq!(image.size(), Size::new(8, 2));
+    /// assert_eq!(image.pixel(Point::new(7, 1)), Some(BinaryColor::On));
+    /// ```
+    pub const fn with_stride(
+        data: &'a [u8],
+        width: u32,
+        height: u32,
+        stride: usize,
+    ) -> Option<Self> {
+        let row_len = width.div_ceil(8) as usize;
+        if stride < row_len {
+            return None;
+        }
+
+        // A stride of zero is only possible for images without pixels.
+        let rows = match data.len().checked_sub(row_len) {
+            Some(rest) => match rest.checked_div(stride) {
+                Some(rows) => rows + 1,
+                None => height as usize,
+            },
+            None => 0,
+        };
+        let height = if (height as usize) < rows {
+            height
+        } else {
+            rows as u32
+        };
+
+        Some(Self {
+            bits: Bits::new(data, width, BitOrder::MsbFirst).with_stride(stride),
+            size: Size::new(width, height),
+        })
+    }
+
+    /// Read the pixels of every byte in the given order
+    pub const fn with_bit_order(mut self, order: BitOrder) -> Self {
+        self.bits = self.bits.with_order(order);
+        self
+    }
+}
+
+impl OriginDimensions for RawImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for RawImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits.get(p).into())
+    }
+}
+
+impl RowSummary for RawImage<'_> {
+    fn is_row_blank(&self, y: u32) -> bool {
+        y >= self.size.height || self.bits.is_row_blank(y, self.size.width)
+    }
+}
+
+/// Column-major 1bpp image with a configurable bit order.
+///
+/// Every column is stored from top to bottom and padded to a full byte,
+/// which is how some segment LCD tools and column oriented displays
+/// organize their data. This avoids rotating such assets offline.
+///
+/// ```rust
+/// use embedded_graphics::{image::GetPixel, pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::source::ColumnMajorImage;
+///
+/// // Two columns of three pixels, the second one is lit at the bottom
+/// let image = ColumnMajorImage::new(&[0b0000_0000, 0b0010_0000], 2, 3);
+/// assert_eq!(image.pixel(Point::new(1, 2)), Some(BinaryColor::On));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColumnMajorImage<'a> {
+    bits: Bits<'a>,
+    size: Size,
+}
+
+impl<'a> ColumnMajorImage<'a> {
+    /// Create a new MSB-first image with columns padded to a full byte
+    ///
+    /// If `data` holds fewer than `width` columns, the width is reduced to
+    /// the number of complete columns.
+    pub const fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        let bits = Bits::new(data, height, BitOrder::MsbFirst);
+        // The bits are stored transposed, with a column in every row.
+        let transposed = bits.clamp(Size::new(height, width));
+        Self {
+            bits,
+            size: Size::new(transposed.height, height),
+        }
+    }
+
+    /// Read the pixels of every byte in the given order
+    ///
+    /// [`BitOrder::LsbFirst`] stores the topmost pixel in the least
+    /// significant bit.
+    pub const fn with_bit_order(mut self, order: BitOrder) -> Self {
+        self.bits = self.bits.with_order(order);
+        self
+    }
+}
+
+impl OriginDimensions for ColumnMajorImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for ColumnMajorImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits.get(Point::new(p.y, p.x)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_order() {
+        let data = [0b1000_0000, 0b0000_0001];
+        let msb = RawImage::new(&data, 3, 2);
+        let lsb = msb.with_bit_order(BitOrder::LsbFirst);
+
+        assert_eq!(msb.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(msb.pixel(Point::new(0, 1)), Some(BinaryColor::Off));
+        assert_eq!(lsb.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(lsb.pixel(Point::new(0, 1)), Some(BinaryColor::On));
+        assert!(lsb.is_row_blank(0));
+    }
+
+    #[test]
+    fn test_with_stride() {
+        let data = [0b1000_0000, 0xff, 0xff, 0b0100_0000];
+        let image = RawImage::with_stride(&data, 2, 4, 3).unwrap();
+        assert_eq!(image.size(), Size::new(2, 2));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert!(RawImage::with_stride(&data, 9, 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_column_major() {
+        let image = ColumnMajorImage::new(&[0b1000_0000, 0b0100_0000, 0xff], 3, 2);
+        assert_eq!(image.size(), Size::new(3, 2));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+
+        let truncated = ColumnMajorImage::new(&[0x00, 0x00], 3, 2);
+        assert_eq!(truncated.size(), Size::new(2, 2));
+    }
+}
@@ -0,0 +1,49 @@
+/// Access to packed pixel data.
+///
+/// Sources read their pixel data one byte at a time through this trait.
+/// Byte slices and arrays are read directly, other implementations can
+/// fetch bytes with special instructions, like `LPM` for AVR program memory
+/// or reads through a flash controller on platforms without
+/// execute-in-place access.
+pub trait PixelDataReader {
+    /// Number of bytes available
+    fn len(&self) -> usize;
+
+    /// Returns `true` if no bytes are available
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the byte at `index`, which is smaller than [`PixelDataReader::len`]
+    fn read(&self, index: usize) -> u8;
+}
+
+impl PixelDataReader for [u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn read(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl<const N: usize> PixelDataReader for [u8; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn read(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl<R: PixelDataReader + ?Sized> PixelDataReader for &R {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn read(&self, index: usize) -> u8 {
+        (**self).read(index)
+    }
+}
@@ -0,0 +1,252 @@
+//! Run-length encoded 1bpp images.
+//!
+//! The container starts with a 4 byte [`MAGIC`], followed by the width and
+//! height as little endian `u16`. The rest of the data is the RLE payload:
+//! every byte describes a run of pixels in row-major order, with the most
+//! significant bit holding the color and the lower seven bits holding the
+//! run length minus one. Runs may continue across rows.
+
+use super::bits;
+use core::{cell::Cell, fmt};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Magic bytes at the start of every RLE image
+pub const MAGIC: [u8; 4] = *b"RLE1";
+
+const HEADER_LEN: usize = MAGIC.len() + 4;
+#[cfg(feature = "std")]
+const MAX_RUN: u32 = 0x80;
+
+/// Errors returned when reading or writing RLE images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The data doesn't start with [`MAGIC`]
+    BadMagic,
+    /// The data ends before all pixels have been described
+    Truncated,
+    /// The image is too large for the container format
+    TooLarge,
+    /// The data doesn't match the expected [`crc32`](super::crc32)
+    Checksum,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::BadMagic => "missing RLE magic bytes",
+            Error::Truncated => "RLE data is truncated",
+            Error::TooLarge => "image is too large for RLE",
+            Error::Checksum => "RLE checksum mismatch",
+        })
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Position of the decoder within the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+struct Cursor {
+    /// Index of the first pixel covered by the run at `offset`
+    pixel: u32,
+    offset: usize,
+}
+
+/// Run-length encoded binary image.
+///
+/// The decoder remembers its position in the payload, so drawing the image
+/// row by row decodes every run only once. Reading a pixel before the
+/// remembered position restarts decoding at the beginning of the payload.
+#[derive(Debug, Clone)]
+pub struct RleImage<'a> {
+    payload: &'a [u8],
+    size: Size,
+    cursor: Cell<Cursor>,
+}
+
+impl<'a> RleImage<'a> {
+    /// Parse an RLE image
+    ///
+    /// The payload is checked once, so reading pixels afterwards can't fail.
+    pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        let header = data.get(..HEADER_LEN).ok_or(Error::Truncated)?;
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(Error::BadMagic);
+        }
+
+        let width = u16::from_le_bytes([header[4], header[5]]);
+        let height = u16::from_le_bytes([header[6], header[7]]);
+        let payload = &data[HEADER_LEN..];
+
+        let total = u32::from(width) * u32::from(height);
+        let described = payload.iter().map(|&run| run_len(run)).sum::<u32>();
+        if described < total {
+            return Err(Error::Truncated);
+        }
+
+        Ok(Self {
+            payload,
+            size: Size::new(width.into(), height.into()),
+            cursor: Cell::new(Cursor::default()),
+        })
+    }
+
+    /// Parse an RLE image after checking its [`crc32`](super::crc32)
+    ///
+    /// The checksum covers the whole container, including the header. Use
+    /// this for data that may have been corrupted in storage or transit.
+    pub fn new_checked(data: &'a [u8], crc: u32) -> Result<Self, Error> {
+        if super::crc32(data) != crc {
+            return Err(Error::Checksum);
+        }
+        Self::new(data)
+    }
+
+    fn decode(&self, index: u32) -> BinaryColor {
+        let mut cursor = self.cursor.get();
+        if index < cursor.pixel {
+            cursor = Cursor::default();
+        }
+
+        loop {
+            let run = self.payload[cursor.offset];
+            if index < cursor.pixel + run_len(run) {
+                self.cursor.set(cursor);
+                return run_color(run);
+            }
+            cursor.pixel += run_len(run);
+            cursor.offset += 1;
+        }
+    }
+}
+
+// The cursor is decoder state, equal images can be at different positions.
+impl PartialEq for RleImage<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.payload == other.payload && self.size == other.size
+    }
+}
+
+impl Eq for RleImage<'_> {}
+
+impl OriginDimensions for RleImage<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for RleImage<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.decode(p.y as u32 * self.size.width + p.x as u32))
+    }
+}
+
+const fn run_len(run: u8) -> u32 {
+    (run & 0x7f) as u32 + 1
+}
+
+fn run_color(run: u8) -> BinaryColor {
+    (run & 0x80 != 0).into()
+}
+
+/// Encode a binary image as RLE
+#[cfg(feature = "std")]
+pub fn encode<T>(image: &T) -> Result<Vec<u8>, Error>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    use embedded_graphics::prelude::{Dimensions, PointsIter};
+
+    let size = image.size();
+    let width = u16::try_from(size.width).map_err(|_| Error::TooLarge)?;
+    let height = u16::try_from(size.height).map_err(|_| Error::TooLarge)?;
+
+    let mut out = Vec::with_capacity(HEADER_LEN);
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+
+    let mut run: Option<(BinaryColor, u32)> = None;
+    for point in image.bounding_box().points() {
+        let color = image.pixel(point).unwrap_or(BinaryColor::Off);
+        run = match run {
+            Some((current, len)) if current == color && len < MAX_RUN => Some((current, len + 1)),
+            Some((current, len)) => {
+                out.push(encode_run(current, len));
+                Some((color, 1))
+            }
+            None => Some((color, 1)),
+        };
+    }
+    if let Some((current, len)) = run {
+        out.push(encode_run(current, len));
+    }
+
+    Ok(out)
+}
+
+#[cfg(feature = "std")]
+fn encode_run(color: BinaryColor, len: u32) -> u8 {
+    let bit = if color.is_on() { 0x80 } else { 0x00 };
+    bit | (len - 1) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rle_decode() {
+        // 3x2: runs of 2 on, 3 off, 1 on
+        let data = [b'R', b'L', b'E', b'1', 3, 0, 2, 0, 0x81, 0x02, 0x80];
+        let image = RleImage::new(&data).unwrap();
+        assert_eq!(image.size(), Size::new(3, 2));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(2, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(2, 1)), Some(BinaryColor::On));
+        // Going backwards restarts the decoder.
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+
+        image.pixel(Point::new(2, 1));
+        assert_eq!(image, RleImage::new(&data).unwrap());
+    }
+
+    #[test]
+    fn test_rle_errors() {
+        assert_eq!(
+            RleImage::new(b"RLE0\x01\x00\x01\x00\x00"),
+            Err(Error::BadMagic)
+        );
+        assert_eq!(
+            RleImage::new(b"RLE1\x02\x00\x01\x00\x00"),
+            Err(Error::Truncated)
+        );
+
+        let data = b"RLE1\x01\x00\x01\x00\x80";
+        let crc = crate::source::crc32(data);
+        assert!(RleImage::new_checked(data, crc).is_ok());
+        assert_eq!(RleImage::new_checked(data, !crc), Err(Error::Checksum));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rle_roundtrip() {
+        use crate::source::AdafruitBitmap;
+        use embedded_graphics::prelude::{Dimensions, PointsIter};
+
+        let bitmap = AdafruitBitmap::new(&[0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0xf0, 0x0f], 32, 2);
+        let encoded = encode(&bitmap).unwrap();
+        let image = RleImage::new(&encoded).unwrap();
+        for point in bitmap.bounding_box().points() {
+            assert_eq!(image.pixel(point), bitmap.pixel(point));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::ReadStorage;
+use embedded_sdmmc::{BlockDevice, Error, File, TimeSource};
+
+/// Read image files straight from an SD card.
+///
+/// The file is read with a seek followed by as many reads as needed, so a
+/// [`StorageImage`](super::StorageImage) decodes it row by row without
+/// loading the whole file.
+///
+/// ```rust,no_run
+/// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::{Image, source::StorageImage};
+/// use embedded_sdmmc::{BlockDevice, Mode, TimeSource, VolumeIdx, VolumeManager};
+///
+/// fn draw_logo<D, T, Display>(volumes: &VolumeManager<D, T>, display: &mut Display)
+/// where
+///     D: BlockDevice,
+///     T: TimeSource,
+///     Display: DrawTarget<Color = BinaryColor>,
+/// {
+///     let volume = volumes.open_volume(VolumeIdx(0)).unwrap();
+///     let root = volume.open_root_dir().unwrap();
+///     let file = root.open_file_in_dir("LOGO.PBM", Mode::ReadOnly).unwrap();
+///
+///     let mut buffer = [0; 64];
+///     let logo = StorageImage::from_pbm(file, &mut buffer).unwrap();
+///     Image::new(&logo, Point::zero(), BinaryColor::On).draw(display).ok();
+/// }
+/// ```
+impl<D, T, const MAX_DIRS: usize, const MAX_FILES: usize, const MAX_VOLUMES: usize> ReadStorage
+    for File<'_, D, T, MAX_DIRS, MAX_FILES, MAX_VOLUMES>
+where
+    D: BlockDevice,
+    T: TimeSource,
+{
+    type Error = Error<D::Error>;
+
+    fn read(&mut self, offset: u32, mut bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.seek_from_start(offset)?;
+        while !bytes.is_empty() {
+            match File::read(self, bytes)? {
+                0 => return Err(Error::EndOfFile),
+                n => bytes = &mut bytes[n..],
+            }
+        }
+        Ok(())
+    }
+}
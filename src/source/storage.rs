@@ -0,0 +1,394 @@
+use super::{
+    bits::{self, Bits},
+    cache::RowCache,
+};
+use crate::view::LumaWeights;
+use core::cell::RefCell;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Byte addressable storage, like an external SPI flash.
+///
+/// This has the same shape as `embedded_storage::ReadStorage`, so flash
+/// drivers can be adapted with a one line `read` implementation.
+pub trait ReadStorage {
+    /// Error returned by the storage driver
+    type Error;
+
+    /// Read `bytes.len()` bytes starting at `offset`
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<S: ReadStorage + ?Sized> ReadStorage for &mut S {
+    type Error = S::Error;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read(offset, bytes)
+    }
+}
+
+/// Errors returned when opening image files from a [`ReadStorage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageError<E> {
+    /// The storage driver failed
+    Storage(E),
+    /// The file header is malformed
+    BadHeader,
+    /// The file uses a feature that isn't supported, like more than 1bpp
+    Unsupported,
+    /// The row buffer is too short for the image width
+    BufferTooSmall,
+}
+
+/// Row layout of the pixel data in storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Layout {
+    offset: u32,
+    /// Distance between the starts of two rows in bytes
+    stride: u32,
+    /// The last row is stored first
+    bottom_up: bool,
+    /// Set bits are `Off`
+    invert: bool,
+}
+
+/// Binary image read on demand from a [`ReadStorage`].
+///
+/// The pixel data uses the same layout as
+/// [`ImageRaw<BinaryColor>`](embedded_graphics::image::ImageRaw) and starts
+/// at `offset` in the storage. The last rows are cached in a caller
+/// provided buffer, so the image is fetched row by row while drawing and
+/// never copied to RAM as a whole. A buffer of
+/// [`StorageImage::cached_buffer_len`] bytes caches more than one row, which
+/// helps when the draw loop revisits rows, like under rotating views.
+///
+/// Pixels in rows that can't be read are returned as `None`.
+///
+/// Binary PBM and 1bpp BMP files can be opened with
+/// [`StorageImage::from_pbm`] and [`StorageImage::from_bmp`], for example
+/// from a file on an SD card with the `embedded-sdmmc` feature.
+#[derive(Debug)]
+pub struct StorageImage<'b, S> {
+    size: Size,
+    state: RefCell<State<'b, S>>,
+}
+
+impl<'b, S> StorageImage<'b, S>
+where
+    S: ReadStorage,
+{
+    /// Size of the buffer required by [`StorageImage::new`]
+    pub const fn buffer_len(width: u32) -> usize {
+        Self::cached_buffer_len(width, 1)
+    }
+
+    /// Size of a buffer that caches `rows` rows
+    ///
+    /// At most 8 rows are cached, extra space in the buffer is unused.
+    pub const fn cached_buffer_len(width: u32, rows: usize) -> usize {
+        RowCache::buffer_len(width, rows)
+    }
+
+    /// Create a new image stored at `offset`
+    ///
+    /// Returns `None` if `buffer` is shorter than [`StorageImage::buffer_len`].
+    pub fn new(
+        storage: S,
+        offset: u32,
+        width: u32,
+        height: u32,
+        buffer: &'b mut [u8],
+    ) -> Option<Self> {
+        let layout = Layout {
+            offset,
+            stride: Self::buffer_len(width) as u32,
+            bottom_up: false,
+            invert: false,
+        };
+        Self::with_layout(storage, layout, Size::new(width, height), buffer)
+    }
+
+    /// Open a binary (`P4`) PBM file
+    ///
+    /// Black pixels in the file are `Off`, white pixels are `On`.
+    pub fn from_pbm(mut storage: S, buffer: &'b mut [u8]) -> Result<Self, StorageError<S::Error>> {
+        let mut header = Header::new(&mut storage);
+        if header.byte()? != b'P' || header.byte()? != b'4' {
+            return Err(StorageError::BadHeader);
+        }
+        let width = header.number()?;
+        let height = header.number()?;
+        // The header ends with a single whitespace character.
+        let offset = header.offset + 1;
+
+        let layout = Layout {
+            offset,
+            stride: Self::buffer_len(width) as u32,
+            bottom_up: false,
+            invert: true,
+        };
+        Self::with_layout(storage, layout, Size::new(width, height), buffer)
+            .ok_or(StorageError::BufferTooSmall)
+    }
+
+    /// Open an uncompressed 1bpp BMP file
+    ///
+    /// The lighter of the two palette colors is `On`.
+    pub fn from_bmp(mut storage: S, buffer: &'b mut [u8]) -> Result<Self, StorageError<S::Error>> {
+        let mut file = [0; 14 + 40];
+        storage.read(0, &mut file).map_err(StorageError::Storage)?;
+        if file[..2] != *b"BM" {
+            return Err(StorageError::BadHeader);
+        }
+
+        let u16_at = |i: usize| u16::from_le_bytes([file[i], file[i + 1]]);
+        let u32_at =
+            |i: usize| u32::from_le_bytes([file[i], file[i + 1], file[i + 2], file[i + 3]]);
+        let offset = u32_at(10);
+        let dib_len = u32_at(14);
+        let width = u32_at(18) as i32;
+        let height = u32_at(22) as i32;
+        if dib_len < 40 || width < 0 {
+            return Err(StorageError::BadHeader);
+        }
+        if u16_at(28) != 1 || u32_at(30) != 0 {
+            return Err(StorageError::Unsupported);
+        }
+
+        let mut palette = [0; 8];
+        storage
+            .read(14 + dib_len, &mut palette)
+            .map_err(StorageError::Storage)?;
+        let luma = |i: usize| LumaWeights::BT601.luma(palette[i + 2], palette[i + 1], palette[i]);
+
+        let width = width as u32;
+        let layout = Layout {
+            offset,
+            stride: width.div_ceil(32) * 4,
+            bottom_up: height > 0,
+            invert: luma(0) > luma(4),
+        };
+        let size = Size::new(width, height.unsigned_abs());
+        Self::with_layout(storage, layout, size, buffer).ok_or(StorageError::BufferTooSmall)
+    }
+
+    fn with_layout(storage: S, layout: Layout, size: Size, buffer: &'b mut [u8]) -> Option<Self> {
+        Some(Self {
+            size,
+            state: RefCell::new(State {
+                storage,
+                layout,
+                cache: RowCache::new(buffer, size.width)?,
+                size,
+            }),
+        })
+    }
+
+    /// Returns the underlying storage
+    pub fn into_inner(self) -> S {
+        self.state.into_inner().storage
+    }
+}
+
+impl<S> OriginDimensions for StorageImage<'_, S> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<S> GetPixel for StorageImage<'_, S>
+where
+    S: ReadStorage,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !bits::contains(self.size, p) {
+            return None;
+        }
+
+        let mut state = self.state.borrow_mut();
+        let row = state.seek(p.y as u32)?;
+        Some(row.get(Point::new(p.x, 0)).into())
+    }
+}
+
+#[derive(Debug)]
+struct State<'b, S> {
+    storage: S,
+    layout: Layout,
+    cache: RowCache<'b>,
+    size: Size,
+}
+
+impl<S: ReadStorage> State<'_, S> {
+    fn seek(&mut self, y: u32) -> Option<Bits<'_>> {
+        if self.cache.get(y).is_none() {
+            let layout = self.layout;
+            let stored = if layout.bottom_up {
+                self.size.height - 1 - y
+            } else {
+                y
+            };
+            let offset = layout
+                .offset
+                .checked_add(stored.checked_mul(layout.stride)?)?;
+            let row = self.cache.slot();
+            self.storage.read(offset, row).ok()?;
+            if layout.invert {
+                row.iter_mut().for_each(|byte| *byte = !*byte);
+            }
+            self.cache.commit(y);
+        }
+
+        self.cache.get(y)
+    }
+}
+
+/// Reader for the text header of PBM files.
+struct Header<'s, S> {
+    storage: &'s mut S,
+    offset: u32,
+}
+
+impl<'s, S: ReadStorage> Header<'s, S> {
+    const fn new(storage: &'s mut S) -> Self {
+        Self { storage, offset: 0 }
+    }
+
+    fn byte(&mut self) -> Result<u8, StorageError<S::Error>> {
+        let mut byte = [0];
+        self.storage
+            .read(self.offset, &mut byte)
+            .map_err(StorageError::Storage)?;
+        self.offset += 1;
+        Ok(byte[0])
+    }
+
+    /// Parse a decimal number, skipping whitespace and comments before it
+    ///
+    /// The offset is left at the whitespace following the number.
+    fn number(&mut self) -> Result<u32, StorageError<S::Error>> {
+        let mut byte = self.byte()?;
+        loop {
+            match byte {
+                b'#' => {
+                    while byte != b'\n' {
+                        byte = self.byte()?;
+                    }
+                }
+                b' ' | b'\t' | b'\r' | b'\n' => byte = self.byte()?,
+                _ => break,
+            }
+        }
+
+        let mut value = 0u32;
+        let mut digits = 0;
+        while byte.is_ascii_digit() {
+            value = value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add((byte - b'0').into()))
+                .ok_or(StorageError::BadHeader)?;
+            digits += 1;
+            byte = self.byte()?;
+        }
+        if digits == 0 || !byte.is_ascii_whitespace() {
+            return Err(StorageError::BadHeader);
+        }
+        self.offset -= 1;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Flash<'a> {
+        data: &'a [u8],
+        reads: usize,
+    }
+
+    impl ReadStorage for Flash<'_> {
+        type Error = ();
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), ()> {
+            let offset = offset as usize;
+            let data = self.data.get(offset..offset + bytes.len()).ok_or(())?;
+            bytes.copy_from_slice(data);
+            self.reads += 1;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_storage_reads_rows() {
+        let flash = Flash {
+            data: &[0xff, 0xff, 0x80, 0x00, 0x01, 0x00],
+            reads: 0,
+        };
+        let mut buffer = [0; StorageImage::<Flash>::buffer_len(9)];
+        let image = StorageImage::new(flash, 2, 9, 3, &mut buffer).unwrap();
+
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(8, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(7, 1)), Some(BinaryColor::On));
+        // The last row is past the end of the flash.
+        assert_eq!(image.pixel(Point::new(0, 2)), None);
+        assert_eq!(image.into_inner().reads, 2);
+
+        let flash = Flash {
+            data: &[0xff, 0x00, 0xff],
+            reads: 0,
+        };
+        let mut buffer = [0; StorageImage::<Flash>::cached_buffer_len(8, 2)];
+        let image = StorageImage::new(flash, 0, 8, 3, &mut buffer).unwrap();
+        for y in [0, 1, 0, 1, 2, 1] {
+            image.pixel(Point::new(0, y));
+        }
+        assert_eq!(image.into_inner().reads, 3);
+    }
+
+    #[test]
+    fn test_storage_pbm_and_bmp() {
+        let pbm = Flash {
+            data: b"P4\n# logo\n3 2\n\x40\xa0",
+            reads: 0,
+        };
+        let mut buffer = [0; 1];
+        let image = StorageImage::from_pbm(pbm, &mut buffer).unwrap();
+        assert_eq!(image.size(), Size::new(3, 2));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+
+        // 3x2 bottom-up BMP with a black and white palette.
+        let mut bmp = [0u8; 14 + 40 + 8 + 8];
+        bmp[..2].copy_from_slice(b"BM");
+        bmp[10] = 62;
+        bmp[14] = 40;
+        bmp[18] = 3;
+        bmp[22] = 2;
+        bmp[26] = 1;
+        bmp[28] = 1;
+        bmp[58..62].copy_from_slice(&[0xff, 0xff, 0xff, 0x00]);
+        bmp[62] = 0x40;
+        bmp[66] = 0xa0;
+        let image = StorageImage::from_bmp(
+            Flash {
+                data: &bmp,
+                reads: 0,
+            },
+            &mut buffer,
+        )
+        .unwrap();
+        assert_eq!(image.size(), Size::new(3, 2));
+        assert_eq!(image.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(image.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(image.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+    }
+}
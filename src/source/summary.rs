@@ -0,0 +1,17 @@
+/// Image source that can tell if a row is blank without checking every pixel.
+///
+/// Sparse images, like signatures and line charts, are mostly empty.
+/// [`Image::draw_sparse`](crate::Image::draw_sparse) uses this to skip
+/// their blank rows.
+pub trait RowSummary {
+    /// Returns `true` if no pixel of row `y` is `On`
+    ///
+    /// Rows outside the image are blank.
+    fn is_row_blank(&self, y: u32) -> bool;
+}
+
+impl<S: RowSummary + ?Sized> RowSummary for &S {
+    fn is_row_blank(&self, y: u32) -> bool {
+        (**self).is_row_blank(y)
+    }
+}
@@ -0,0 +1,111 @@
+use super::bits::{self, BitOrder, Bits};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// XBM bitmap as drawn by u8g2 `drawXBM`.
+///
+/// Pixels are stored LSB-first and every row is padded to a full byte, so
+/// arrays from `.xbm` files or u8g2 sketches can be used unmodified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Xbm<'a> {
+    bits: Bits<'a>,
+    size: Size,
+}
+
+impl<'a> Xbm<'a> {
+    /// Create a new bitmap from `drawXBM` compatible data
+    ///
+    /// If `data` holds fewer than `height` rows, the height is reduced to
+    /// the number of complete rows.
+    pub const fn new(data: &'a [u8], width: u32, height: u32) -> Self {
+        let bits = Bits::new(data, width, BitOrder::LsbFirst);
+        Self {
+            bits,
+            size: bits.clamp(Size::new(width, height)),
+        }
+    }
+}
+
+impl OriginDimensions for Xbm<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for Xbm<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits.get(p).into())
+    }
+}
+
+/// Bitmap as drawn by u8g2 `drawBitmap`.
+///
+/// u8g2 describes the width of these bitmaps as a count of bytes per row,
+/// so the image is always a multiple of eight pixels wide. Pixels are
+/// stored MSB-first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct U8g2Bitmap<'a> {
+    bits: Bits<'a>,
+    size: Size,
+}
+
+impl<'a> U8g2Bitmap<'a> {
+    /// Create a new bitmap from `drawBitmap` compatible data
+    ///
+    /// `cnt` is the number of bytes per row, as passed to `drawBitmap`. If
+    /// `data` holds fewer than `height` rows, the height is reduced to the
+    /// number of complete rows. The width saturates at `u32::MAX`.
+    pub const fn new(data: &'a [u8], cnt: u32, height: u32) -> Self {
+        let width = cnt.saturating_mul(8);
+        let bits = Bits::new(data, width, BitOrder::MsbFirst);
+        Self {
+            bits,
+            size: bits.clamp(Size::new(width, height)),
+        }
+    }
+}
+
+impl OriginDimensions for U8g2Bitmap<'_> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl GetPixel for U8g2Bitmap<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        bits::contains(self.size, p).then(|| self.bits.get(p).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xbm_is_lsb_first() {
+        let xbm = Xbm::new(&[0b0000_0001, 0b0000_0100], 3, 2);
+        assert_eq!(xbm.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(xbm.pixel(Point::new(2, 0)), Some(BinaryColor::Off));
+        assert_eq!(xbm.pixel(Point::new(2, 1)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn test_u8g2_bitmap_width_from_byte_count() {
+        let bitmap = U8g2Bitmap::new(&[0x80, 0x01, 0x00, 0x00], 2, 2);
+        assert_eq!(bitmap.size(), Size::new(16, 2));
+        assert_eq!(bitmap.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(bitmap.pixel(Point::new(15, 0)), Some(BinaryColor::On));
+        assert_eq!(bitmap.pixel(Point::new(15, 1)), Some(BinaryColor::Off));
+
+        let huge = U8g2Bitmap::new(&[0xff], u32::MAX, 1);
+        assert_eq!(huge.size(), Size::new(u32::MAX, 0));
+    }
+}
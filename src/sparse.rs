@@ -0,0 +1,79 @@
+use crate::{Image, source::RowSummary};
+use embedded_graphics::{
+    Pixel,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+impl<T, C> Image<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor> + RowSummary,
+    C: PixelColor,
+{
+    /// Draw the image, skipping blank rows without checking their pixels
+    ///
+    /// This speeds up sparse images, like signatures and line charts. Blank
+    /// rows of images with a background are filled with a single call to
+    /// [`DrawTarget::fill_solid`].
+    pub fn draw_sparse<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let size = self.image.size();
+        // Blank rows are `Off`, unless the image is inverted.
+        let blank = if self.inverted {
+            Some(self.color)
+        } else {
+            self.background
+        };
+
+        for y in 0..size.height {
+            if self.image.is_row_blank(y) {
+                if let Some(color) = blank {
+                    let row = Rectangle::new(
+                        self.position + Point::new(0, y as i32),
+                        Size::new(size.width, 1),
+                    );
+                    target.fill_solid(&row, color)?;
+                }
+                continue;
+            }
+
+            target.draw_iter((0..size.width).filter_map(|x| {
+                let point = Point::new(x as i32, y as i32);
+                Some(Pixel(self.position + point, self.pixel_at(point)?))
+            }))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::BinaryBuffer;
+    use embedded_graphics::{Drawable, mock_display::MockDisplay};
+
+    #[test]
+    fn test_draw_sparse_matches_draw() {
+        let buffer = BinaryBuffer::new([0x00, 0b0100_0000, 0x00], 3, 3).unwrap();
+
+        for background in [None, Some(BinaryColor::Off)] {
+            let mut image = Image::new(&buffer, Point::new(1, 1), BinaryColor::On);
+            if let Some(color) = background {
+                image = image.with_background(color);
+            }
+
+            let mut expected = MockDisplay::new();
+            image.draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            image.draw_sparse(&mut display).unwrap();
+            display.assert_eq(&expected);
+        }
+    }
+}
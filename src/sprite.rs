@@ -0,0 +1,223 @@
+use crate::{AnyTintedImage, fixed::Fixed};
+use embedded_graphics::{
+    Drawable,
+    geometry::Dimensions,
+    prelude::{DrawTarget, PixelColor, Point, Transform},
+    primitives::Rectangle,
+};
+
+/// Moving element with a subpixel position and a velocity.
+///
+/// The content is usually an [`Image`](crate::Image), but anything that can
+/// be translated works. Velocities are in pixels per second and
+/// [`Sprite::update`] takes the elapsed time in milliseconds, so the motion
+/// doesn't depend on the frame rate. The content is kept at the rounded
+/// position.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, Sprite, fixed::Fixed};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xf0; 4], 4);
+/// let logo = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+///
+/// let mut sprite = Sprite::new(logo).with_velocity(Fixed::from_int(40), Fixed::from_int(20));
+/// sprite.update(100);
+/// sprite.bounce(&display.bounding_box());
+/// assert_eq!(sprite.position(), Point::new(4, 2));
+/// sprite.draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Sprite<I> {
+    content: I,
+    x: Fixed,
+    y: Fixed,
+    vx: Fixed,
+    vy: Fixed,
+}
+
+impl<I> Sprite<I>
+where
+    I: Transform + Dimensions,
+{
+    /// Create a resting sprite at the position of its content
+    pub fn new(content: I) -> Self {
+        let position = content.bounding_box().top_left;
+        Self {
+            content,
+            x: Fixed::from_int(position.x),
+            y: Fixed::from_int(position.y),
+            vx: Fixed::ZERO,
+            vy: Fixed::ZERO,
+        }
+    }
+
+    /// Set the velocity in pixels per second
+    pub fn with_velocity(mut self, vx: Fixed, vy: Fixed) -> Self {
+        self.set_velocity(vx, vy);
+        self
+    }
+
+    /// Returns the content
+    pub const fn content(&self) -> &I {
+        &self.content
+    }
+
+    /// Returns the content mutably
+    ///
+    /// Moving the content directly desyncs it from the sprite position until
+    /// the next move.
+    pub fn content_mut(&mut self) -> &mut I {
+        &mut self.content
+    }
+
+    /// Returns the content, dropping the motion state
+    pub fn into_inner(self) -> I {
+        self.content
+    }
+
+    /// Returns the rounded position of the top left corner
+    pub fn position(&self) -> Point {
+        Point::new(self.x.round(), self.y.round())
+    }
+
+    /// Move the sprite to a position, keeping its velocity
+    pub fn set_position(&mut self, position: Point) {
+        self.x = Fixed::from_int(position.x);
+        self.y = Fixed::from_int(position.y);
+        self.sync();
+    }
+
+    /// Returns the velocity in pixels per second
+    pub const fn velocity(&self) -> (Fixed, Fixed) {
+        (self.vx, self.vy)
+    }
+
+    /// Set the velocity in pixels per second
+    pub fn set_velocity(&mut self, vx: Fixed, vy: Fixed) {
+        self.vx = vx;
+        self.vy = vy;
+    }
+
+    /// Advance the sprite by `dt` milliseconds
+    pub fn update(&mut self, dt: u32) {
+        self.x = self.x + self.vx.over_millis(dt);
+        self.y = self.y + self.vy.over_millis(dt);
+        self.sync();
+    }
+
+    /// Keep the sprite inside `area`, reversing its velocity at the edges
+    ///
+    /// Returns `true` if the sprite hit an edge.
+    pub fn bounce(&mut self, area: &Rectangle) -> bool {
+        let size = self.content.bounding_box().size;
+        let min = area.top_left;
+        let max = min + area.size - size;
+        let (x, vx, hit_x) = reflect(self.x, self.vx, min.x, max.x.max(min.x));
+        let (y, vy, hit_y) = reflect(self.y, self.vy, min.y, max.y.max(min.y));
+
+        (self.x, self.vx, self.y, self.vy) = (x, vx, y, vy);
+        self.sync();
+        hit_x || hit_y
+    }
+
+    /// Move the content to the rounded position
+    fn sync(&mut self) {
+        let offset = self.position() - self.content.bounding_box().top_left;
+        if offset != Point::zero() {
+            self.content.translate_mut(offset);
+        }
+    }
+}
+
+/// Clamp a coordinate to `min..=max`, reversing the velocity if it moves further out
+fn reflect(value: Fixed, velocity: Fixed, min: i32, max: i32) -> (Fixed, Fixed, bool) {
+    let (min, max) = (Fixed::from_int(min), Fixed::from_int(max));
+    if value < min {
+        (min, velocity.max(-velocity), true)
+    } else if value > max {
+        (max, velocity.min(-velocity), true)
+    } else {
+        (value, velocity, false)
+    }
+}
+
+impl<I> Drawable for Sprite<I>
+where
+    I: Drawable,
+{
+    type Color = I::Color;
+    type Output = I::Output;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.content.draw(target)
+    }
+}
+
+impl<I> Dimensions for Sprite<I>
+where
+    I: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.content.bounding_box()
+    }
+}
+
+impl<I, C> AnyTintedImage<C> for Sprite<I>
+where
+    I: AnyTintedImage<C>,
+    C: PixelColor,
+{
+    fn area(&self) -> Rectangle {
+        self.content.area()
+    }
+
+    fn color_at(&self, point: Point) -> Option<C> {
+        self.content.color_at(point)
+    }
+
+    fn is_opaque(&self) -> bool {
+        self.content.is_opaque()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{image::ImageRaw, pixelcolor::BinaryColor, prelude::Size};
+
+    #[test]
+    fn test_update_accumulates_subpixels() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let image = Image::new(&raw, Point::new(1, 1), BinaryColor::On);
+        let mut sprite = Sprite::new(image).with_velocity(Fixed::from_int(4), -Fixed::ONE);
+
+        for _ in 0..5 {
+            sprite.update(100);
+        }
+        assert_eq!(sprite.position(), Point::new(3, 1));
+        sprite.update(500);
+        assert_eq!(sprite.content().bounding_box().top_left, Point::new(5, 0));
+    }
+
+    #[test]
+    fn test_bounce_reverses_velocity() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xc0, 0xc0], 2);
+        let image = Image::new(&raw, Point::new(6, 0), BinaryColor::On);
+        let mut sprite = Sprite::new(image).with_velocity(Fixed::from_int(10), Fixed::ZERO);
+        let area = Rectangle::new(Point::zero(), Size::new(8, 8));
+
+        sprite.update(500);
+        assert!(sprite.bounce(&area));
+        assert_eq!(sprite.position(), Point::new(6, 0));
+        assert_eq!(sprite.velocity(), (Fixed::from_int(-10), Fixed::ZERO));
+        assert!(!sprite.bounce(&area));
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{Sprite, geometry};
+use embedded_graphics::{
+    Drawable,
+    draw_target::DrawTargetExt,
+    geometry::Dimensions,
+    prelude::{DrawTarget, Point, Transform},
+    primitives::Rectangle,
+};
+
+/// Stable reference to a sprite in a [`SpritePool`].
+///
+/// Handles of removed sprites stay invalid, even if their slot is reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpriteHandle {
+    index: usize,
+    generation: u32,
+}
+
+/// Fixed capacity pool of up to `N` sprites, drawn in z order.
+///
+/// The draw order is kept sorted when sprites are added or their z order
+/// changes, so updating and drawing a frame costs the same every time. Sprites
+/// with a higher z are drawn on top, sprites with the same z in the order they
+/// were added. Like a [`Scene`](crate::Scene), the pool can be drawn through
+/// a camera, so sprites stay in world coordinates.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, Sprite, SpritePool, fixed::Fixed};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xf0; 4], 4);
+///
+/// let mut pool = SpritePool::<_, 8>::new();
+/// let ship = Sprite::new(Image::new(&raw, Point::zero(), Rgb565::WHITE));
+/// let ship = pool.insert(ship.with_velocity(Fixed::from_int(10), Fixed::ZERO), 1).unwrap();
+/// let enemy = pool.insert(Sprite::new(Image::new(&raw, Point::new(8, 0), Rgb565::RED)), 0).unwrap();
+///
+/// pool.update(1000);
+/// assert_eq!(pool.get(ship).unwrap().position(), Point::new(10, 0));
+/// pool.remove(enemy);
+/// pool.draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpritePool<I, const N: usize> {
+    slots: [Option<Slot<I>>; N],
+    generations: [u32; N],
+    /// Indices of the used slots, sorted by z
+    order: [usize; N],
+    len: usize,
+    camera: Point,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<I> {
+    sprite: Sprite<I>,
+    z: i32,
+}
+
+impl<I, const N: usize> SpritePool<I, N>
+where
+    I: Transform + Dimensions,
+{
+    /// Create an empty pool
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { None }; N],
+            generations: [0; N],
+            order: [0; N],
+            len: 0,
+            camera: Point::zero(),
+        }
+    }
+
+    /// Add a sprite at z order `z`
+    ///
+    /// Returns `None` if the pool is full.
+    pub fn insert(&mut self, sprite: Sprite<I>, z: i32) -> Option<SpriteHandle> {
+        let index = self.slots.iter().position(Option::is_none)?;
+        self.slots[index] = Some(Slot { sprite, z });
+        self.order[self.len] = index;
+        self.len += 1;
+        self.sort_from(self.len - 1);
+
+        Some(SpriteHandle {
+            index,
+            generation: self.generations[index],
+        })
+    }
+
+    /// Remove a sprite, returning it
+    pub fn remove(&mut self, handle: SpriteHandle) -> Option<Sprite<I>> {
+        self.get(handle)?;
+        let slot = self.slots[handle.index].take()?;
+        self.generations[handle.index] = self.generations[handle.index].wrapping_add(1);
+
+        let position = self.position(handle.index);
+        self.order.copy_within(position + 1..self.len, position);
+        self.len -= 1;
+        Some(slot.sprite)
+    }
+
+    /// Returns a sprite, or `None` if the handle is stale
+    pub fn get(&self, handle: SpriteHandle) -> Option<&Sprite<I>> {
+        self.slot(handle).map(|slot| &slot.sprite)
+    }
+
+    /// Returns a sprite mutably, or `None` if the handle is stale
+    pub fn get_mut(&mut self, handle: SpriteHandle) -> Option<&mut Sprite<I>> {
+        if self.generations.get(handle.index) != Some(&handle.generation) {
+            return None;
+        }
+        self.slots[handle.index]
+            .as_mut()
+            .map(|slot| &mut slot.sprite)
+    }
+
+    /// Returns the z order of a sprite
+    pub fn z(&self, handle: SpriteHandle) -> Option<i32> {
+        self.slot(handle).map(|slot| slot.z)
+    }
+
+    /// Change the z order of a sprite
+    ///
+    /// Returns `false` if the handle is stale.
+    pub fn set_z(&mut self, handle: SpriteHandle, z: i32) -> bool {
+        if self.get(handle).is_none() {
+            return false;
+        }
+
+        let position = self.position(handle.index);
+        self.order.copy_within(position + 1..self.len, position);
+        self.order[self.len - 1] = handle.index;
+        if let Some(slot) = &mut self.slots[handle.index] {
+            slot.z = z;
+        }
+        self.sort_from(self.len - 1);
+        true
+    }
+
+    /// Returns the camera position
+    pub const fn camera(&self) -> Point {
+        self.camera
+    }
+
+    /// Draw the world position `camera` at the top left corner of the target
+    pub fn set_camera(&mut self, camera: Point) {
+        self.camera = camera;
+    }
+
+    /// Returns the number of sprites
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the pool holds no sprites
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the sprites in draw order
+    pub fn iter(&self) -> impl Iterator<Item = &Sprite<I>> {
+        self.order[..self.len]
+            .iter()
+            .filter_map(|&index| self.slots[index].as_ref())
+            .map(|slot| &slot.sprite)
+    }
+
+    /// Returns the sprites mutably, in no particular order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Sprite<I>> {
+        self.slots.iter_mut().flatten().map(|slot| &mut slot.sprite)
+    }
+
+    /// Returns the handles of the sprites whose areas intersect `area`
+    ///
+    /// The area is in target coordinates, so it's offset by the camera. The
+    /// sprites are returned in draw order.
+    pub fn query(&self, area: Rectangle) -> impl Iterator<Item = SpriteHandle> + '_ {
+        let area = area.translate(self.camera);
+        self.order[..self.len].iter().filter_map(move |&index| {
+            let slot = self.slots[index].as_ref()?;
+            geometry::overlaps(&slot.sprite.bounding_box(), &area).then_some(SpriteHandle {
+                index,
+                generation: self.generations[index],
+            })
+        })
+    }
+
+    /// Returns the topmost sprite whose area contains `point`
+    ///
+    /// The point is in target coordinates.
+    pub fn query_point(&self, point: Point) -> Option<SpriteHandle> {
+        let point = point + self.camera;
+        self.order[..self.len]
+            .iter()
+            .rev()
+            .find(|&&index| {
+                self.slots[index]
+                    .as_ref()
+                    .is_some_and(|slot| slot.sprite.bounding_box().contains(point))
+            })
+            .map(|&index| SpriteHandle {
+                index,
+                generation: self.generations[index],
+            })
+    }
+
+    /// Advance all sprites by `dt` milliseconds
+    pub fn update(&mut self, dt: u32) {
+        for sprite in self.iter_mut() {
+            sprite.update(dt);
+        }
+    }
+
+    fn slot(&self, handle: SpriteHandle) -> Option<&Slot<I>> {
+        if self.generations.get(handle.index) != Some(&handle.generation) {
+            return None;
+        }
+        self.slots[handle.index].as_ref()
+    }
+
+    /// Returns the position of a slot in the draw order
+    fn position(&self, index: usize) -> usize {
+        self.order[..self.len]
+            .iter()
+            .position(|&other| other == index)
+            .unwrap_or(self.len)
+    }
+
+    fn z_at(&self, position: usize) -> i32 {
+        self.slots[self.order[position]]
+            .as_ref()
+            .map_or(0, |slot| slot.z)
+    }
+
+    /// Move the entry at `position` down until the order is sorted again
+    ///
+    /// Entries with the same z keep their order, so the moved entry ends up
+    /// on top of them.
+    fn sort_from(&mut self, mut position: usize) {
+        while position > 0 && self.z_at(position - 1) > self.z_at(position) {
+            self.order.swap(position - 1, position);
+            position -= 1;
+        }
+    }
+}
+
+impl<I, const N: usize> Default for SpritePool<I, N>
+where
+    I: Transform + Dimensions,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, const N: usize> Drawable for SpritePool<I, N>
+where
+    I: Transform + Dimensions + Drawable,
+{
+    type Color = I::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut target = target.translated(-self.camera);
+        for sprite in self.iter() {
+            sprite.draw(&mut target)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, const N: usize> Dimensions for SpritePool<I, N>
+where
+    I: Transform + Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.iter()
+            .fold(Rectangle::zero(), |area, sprite| {
+                geometry::union(&area, &sprite.bounding_box())
+            })
+            .translate(-self.camera)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Point, Size},
+    };
+
+    #[test]
+    fn test_stale_handles() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let mut pool = SpritePool::<_, 1>::new();
+
+        let first = pool.insert(Sprite::new(image), 0).unwrap();
+        assert!(pool.insert(Sprite::new(image), 0).is_none());
+        assert!(pool.remove(first).is_some());
+
+        let second = pool.insert(Sprite::new(image), 0).unwrap();
+        assert!(pool.get(first).is_none());
+        assert!(pool.remove(first).is_none());
+        assert!(!pool.set_z(first, 1));
+        assert!(pool.get(second).is_some());
+    }
+
+    #[test]
+    fn test_query() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let mut pool = SpritePool::<_, 4>::new();
+        let near = Image::new(&raw, Point::new(1, 1), BinaryColor::On);
+        let far = Image::new(&raw, Point::new(9, 9), BinaryColor::On);
+        let near = pool.insert(Sprite::new(near), 1).unwrap();
+        let far = pool.insert(Sprite::new(far), 0).unwrap();
+
+        let area = Rectangle::new(Point::zero(), Size::new(10, 10));
+        let found = {
+            let mut query = pool.query(area);
+            [query.next(), query.next()]
+        };
+        assert_eq!(found, [Some(far), Some(near)]);
+
+        pool.set_camera(Point::new(8, 8));
+        assert_eq!(pool.query_point(Point::new(1, 1)), Some(far));
+        assert_eq!(pool.query_point(Point::new(-7, -7)), Some(near));
+    }
+
+    #[test]
+    fn test_draw_in_z_order() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let on = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let off = Image::new(&raw, Point::zero(), BinaryColor::Off);
+        let mut pool = SpritePool::<_, 4>::new();
+
+        let bottom = pool.insert(Sprite::new(on), 0).unwrap();
+        pool.insert(Sprite::new(off), 1).unwrap();
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        pool.draw(&mut display).unwrap();
+        display.assert_pattern(&["."]);
+
+        assert!(pool.set_z(bottom, 2));
+        assert_eq!(pool.z(bottom), Some(2));
+        pool.draw(&mut display).unwrap();
+        display.assert_pattern(&["#"]);
+    }
+}
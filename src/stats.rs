@@ -0,0 +1,53 @@
+use embedded_graphics::{
+    Pixel,
+    prelude::{PixelColor, Point},
+};
+
+/// Statistics about a single draw call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DrawStats {
+    /// Number of pixels sent to the target
+    pub pixels: u32,
+    /// Number of horizontal runs of adjacent pixels sent to the target
+    ///
+    /// Most display drivers need to set up a new address window for every
+    /// run, so this is a good estimate of the bus overhead of a draw call.
+    pub runs: u32,
+}
+
+/// Iterator adapter that records statistics about the pixels passing through.
+pub(crate) struct Counted<'s, I> {
+    iter: I,
+    stats: &'s mut DrawStats,
+    last: Option<Point>,
+}
+
+impl<'s, I> Counted<'s, I> {
+    pub(crate) fn new(iter: I, stats: &'s mut DrawStats) -> Self {
+        Self {
+            iter,
+            stats,
+            last: None,
+        }
+    }
+}
+
+impl<I, C> Iterator for Counted<'_, I>
+where
+    I: Iterator<Item = Pixel<C>>,
+    C: PixelColor,
+{
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pixel = self.iter.next()?;
+
+        self.stats.pixels += 1;
+        if self.last != Some(pixel.0 - Point::new(1, 0)) {
+            self.stats.runs += 1;
+        }
+        self.last = Some(pixel.0);
+
+        Some(pixel)
+    }
+}
@@ -0,0 +1,133 @@
+use embedded_graphics::{
+    Pixel,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, Point},
+    primitives::Rectangle,
+};
+
+/// Draw target adapter that clips drawing to the `On` area of a mask.
+///
+/// Pixels outside the mask, or where the mask is `Off`, are dropped. This
+/// allows any drawable to be clipped to an irregular shape.
+///
+/// [`Stenciled::punch_out`] does the opposite and only drops pixels where the
+/// mask is `On`, which cuts holes into whatever is drawn.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     pixelcolor::BinaryColor,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Rectangle},
+/// };
+/// use embedded_graphics_colorcast::Stenciled;
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let mask = ImageRaw::<BinaryColor>::new(&[0b0110_0000, 0b1111_0000], 4);
+///
+/// Rectangle::new(Point::zero(), Size::new(4, 2))
+///     .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+///     .draw(&mut Stenciled::new(&mut display, &mask, Point::zero()))?;
+///
+/// display.assert_pattern(&[" ## ", "####"]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct Stenciled<'a, D, M: ?Sized> {
+    target: &'a mut D,
+    mask: &'a M,
+    position: Point,
+    keep: BinaryColor,
+}
+
+impl<'a, D, M> Stenciled<'a, D, M>
+where
+    D: DrawTarget,
+    M: GetPixel<Color = BinaryColor> + ?Sized,
+{
+    /// Clip drawing to a mask with its top left corner at `position`
+    pub const fn new(target: &'a mut D, mask: &'a M, position: Point) -> Self {
+        Self {
+            target,
+            mask,
+            position,
+            keep: BinaryColor::On,
+        }
+    }
+
+    /// Drop drawing inside the `On` area of a mask with its top left corner at `position`
+    pub const fn punch_out(target: &'a mut D, mask: &'a M, position: Point) -> Self {
+        Self {
+            target,
+            mask,
+            position,
+            keep: BinaryColor::Off,
+        }
+    }
+}
+
+impl<D, M> Dimensions for Stenciled<'_, D, M>
+where
+    D: DrawTarget,
+    M: ?Sized,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D, M> DrawTarget for Stenciled<'_, D, M>
+where
+    D: DrawTarget,
+    M: GetPixel<Color = BinaryColor> + ?Sized,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (mask, position, keep) = (self.mask, self.position, self.keep);
+        self.target
+            .draw_iter(pixels.into_iter().filter(|Pixel(point, _)| {
+                // Points outside the mask count as `Off`.
+                mask.pixel(*point - position).unwrap_or(BinaryColor::Off) == keep
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        image::ImageRaw,
+        mock_display::MockDisplay,
+        pixelcolor::Rgb565,
+        prelude::{RgbColor, Size},
+    };
+
+    #[test]
+    fn test_stenciled_offset() {
+        let mask = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut display = MockDisplay::<Rgb565>::new();
+
+        Stenciled::new(&mut display, &mask, Point::new(1, 0))
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(4, 3)), Rgb565::RED)
+            .unwrap();
+        display.assert_pattern(&[" R ", "  R"]);
+    }
+
+    #[test]
+    fn test_punch_out() {
+        let mask = ImageRaw::<BinaryColor>::new(&[0b1000_0000, 0b0100_0000], 2);
+        let mut display = MockDisplay::<Rgb565>::new();
+
+        Stenciled::punch_out(&mut display, &mask, Point::new(1, 0))
+            .fill_solid(&Rectangle::new(Point::zero(), Size::new(4, 2)), Rgb565::RED)
+            .unwrap();
+        display.assert_pattern(&["R RR", "RR R"]);
+    }
+}
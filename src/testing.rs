@@ -0,0 +1,247 @@
+//! Helpers for golden tests of tinted assets.
+//!
+//! These functions render a drawable into a [`MockDisplay`] and compare the
+//! result with an ASCII pattern, using the same character mapping as
+//! `MockDisplay::assert_pattern`.
+//!
+//! ```rust
+//! use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+//! use embedded_graphics_colorcast::{Image, testing};
+//!
+//! let raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0b1010_0000, 0b0101_0000], 4);
+//! let image = Image::new(&raw, Point::zero(), Rgb565::RED).with_background(Rgb565::BLUE);
+//!
+//! testing::assert_pattern(&image, &["RBRB", "BRBR"]);
+//! ```
+
+use core::fmt;
+use embedded_graphics::{
+    Drawable,
+    mock_display::{ColorMapping, MockDisplay},
+    prelude::{Dimensions, PixelColor, Point, PointsIter},
+};
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+/// Render a drawable into a new `MockDisplay`
+///
+/// # Panics
+///
+/// Panics if the drawable draws outside the display area or draws the same
+/// pixel twice.
+#[track_caller]
+pub fn render<D, C>(drawable: &D) -> MockDisplay<C>
+where
+    D: Drawable<Color = C>,
+    C: PixelColor,
+{
+    let mut display = MockDisplay::new();
+    // Drawing to a `MockDisplay` can't fail.
+    let _ = drawable.draw(&mut display);
+    display
+}
+
+/// Render a drawable and compare the result with a pattern
+///
+/// # Panics
+///
+/// Panics if the rendered pixels don't match the pattern.
+#[track_caller]
+pub fn assert_pattern<D, C>(drawable: &D, pattern: &[&str])
+where
+    D: Drawable<Color = C>,
+    C: PixelColor + ColorMapping,
+{
+    render(drawable).assert_pattern(pattern);
+}
+
+/// Render a drawable and return the result as a pattern
+///
+/// The pattern starts at `(0, 0)` and ends at the bottom right corner of the
+/// drawn pixels, so it can be pasted into [`assert_pattern`].
+#[cfg(feature = "std")]
+#[track_caller]
+pub fn pattern<D, C>(drawable: &D) -> Vec<String>
+where
+    D: Drawable<Color = C>,
+    C: PixelColor + ColorMapping,
+{
+    let display = render(drawable);
+    let Some(bottom_right) = display.affected_area().bottom_right() else {
+        return Vec::new();
+    };
+
+    (0..=bottom_right.y)
+        .map(|y| {
+            (0..=bottom_right.x)
+                .map(|x| {
+                    display
+                        .get_pixel(Point::new(x, y))
+                        .map_or(' ', C::color_to_char)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Single pixel that differs between two renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Change<C> {
+    /// Position of the pixel
+    pub point: Point,
+    /// Color in the expected render, `None` if the pixel wasn't drawn
+    pub expected: Option<C>,
+    /// Color in the actual render, `None` if the pixel wasn't drawn
+    pub actual: Option<C>,
+}
+
+/// Pixel-by-pixel difference between two renders.
+///
+/// The `Display` implementation lists every changed coordinate, using the
+/// `MockDisplay` pattern characters for the colors.
+#[derive(Clone, Copy)]
+pub struct RenderDiff<'a, C>
+where
+    C: PixelColor,
+{
+    expected: &'a MockDisplay<C>,
+    actual: &'a MockDisplay<C>,
+}
+
+impl<'a, C> RenderDiff<'a, C>
+where
+    C: PixelColor,
+{
+    /// Compare two renders
+    pub fn new(expected: &'a MockDisplay<C>, actual: &'a MockDisplay<C>) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Returns an iterator over all changed pixels, in row-major order
+    pub fn changes(&self) -> impl Iterator<Item = Change<C>> + '_ {
+        self.expected.bounding_box().points().filter_map(|point| {
+            let expected = self.expected.get_pixel(point);
+            let actual = self.actual.get_pixel(point);
+            (expected != actual).then_some(Change {
+                point,
+                expected,
+                actual,
+            })
+        })
+    }
+
+    /// Returns `true` if both renders are identical
+    pub fn is_empty(&self) -> bool {
+        self.changes().next().is_none()
+    }
+}
+
+impl<C> fmt::Debug for RenderDiff<'_, C>
+where
+    C: PixelColor + ColorMapping,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenderDiff")
+            .field("expected", self.expected)
+            .field("actual", self.actual)
+            .finish()
+    }
+}
+
+impl<C> fmt::Display for RenderDiff<'_, C>
+where
+    C: PixelColor + ColorMapping,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let char = |color: Option<C>| color.map_or(' ', C::color_to_char);
+        for change in self.changes() {
+            writeln!(
+                f,
+                "({}, {}): expected '{}', found '{}'",
+                change.point.x,
+                change.point.y,
+                char(change.expected),
+                char(change.actual),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Render two drawables and check that they produce the same pixels
+///
+/// # Panics
+///
+/// Panics with a list of changed coordinates if the renders differ.
+#[track_caller]
+pub fn assert_renders_eq<E, A, C>(expected: &E, actual: &A)
+where
+    E: Drawable<Color = C>,
+    A: Drawable<Color = C>,
+    C: PixelColor + ColorMapping,
+{
+    let expected = render(expected);
+    let actual = render(actual);
+    let diff = RenderDiff::new(&expected, &actual);
+    assert!(diff.is_empty(), "renders differ:\n{diff}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Image;
+    use embedded_graphics::{
+        image::ImageRaw,
+        pixelcolor::{BinaryColor, Rgb565},
+        prelude::{Point, RgbColor},
+    };
+
+    #[test]
+    fn test_assert_pattern() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0101_0000], 4);
+        let image = Image::new(&raw, Point::new(1, 0), Rgb565::RED);
+        assert_pattern(&image, &[" R R ", "  R R"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pattern() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0101_0000], 4);
+        let image = Image::new(&raw, Point::new(1, 0), Rgb565::RED);
+        assert_eq!(pattern(&image), [" R R ", "  R R"]);
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1010_0000, 0b0101_0000], 4);
+        let expected = render(&Image::new(&raw, Point::zero(), Rgb565::RED));
+        let actual =
+            render(&Image::new(&raw, Point::zero(), Rgb565::RED).with_background(Rgb565::BLUE));
+
+        let diff = RenderDiff::new(&expected, &actual);
+        assert_eq!(diff.changes().count(), 4);
+        assert_eq!(
+            diff.changes().next(),
+            Some(Change {
+                point: Point::new(1, 0),
+                expected: None,
+                actual: Some(Rgb565::BLUE),
+            })
+        );
+        assert!(RenderDiff::new(&expected, &expected).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_render_diff_display() {
+        use std::string::ToString;
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1000_0000], 2);
+        let expected = render(&Image::new(&raw, Point::zero(), Rgb565::RED));
+        let actual = render(&Image::new(&raw, Point::zero(), Rgb565::GREEN));
+        assert_eq!(
+            RenderDiff::new(&expected, &actual).to_string(),
+            "(0, 0): expected 'R', found 'G'\n"
+        );
+    }
+}
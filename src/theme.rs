@@ -0,0 +1,331 @@
+use crate::Image;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Purpose of a color in a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Role {
+    /// Main color, used for most content
+    #[default]
+    Primary,
+    /// Less prominent content
+    Secondary,
+    /// Color behind the content
+    Background,
+    /// Highlights that should stand out
+    Accent,
+}
+
+/// Set of colors used across a user interface.
+///
+/// Images created with [`Image::themed`] pick their color by [`Role`], so the
+/// whole interface can be recolored by creating them from another theme. To
+/// switch themes at runtime, see [`ThemeSet`].
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, Role, Theme};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// const DARK: Theme<Rgb565> = Theme {
+///     primary: Rgb565::WHITE,
+///     secondary: Rgb565::CSS_GRAY,
+///     background: Rgb565::BLACK,
+///     accent: Rgb565::CSS_ORANGE,
+/// };
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+///
+/// Image::themed(&raw, Point::zero(), &DARK, Role::Accent).draw(&mut display)?;
+/// assert_eq!(display.get_pixel(Point::zero()), Some(Rgb565::CSS_ORANGE));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme<C> {
+    /// Main color, used for most content
+    pub primary: C,
+    /// Less prominent content
+    pub secondary: C,
+    /// Color behind the content
+    pub background: C,
+    /// Highlights that should stand out
+    pub accent: C,
+}
+
+impl<C> Theme<C>
+where
+    C: PixelColor,
+{
+    /// Returns the color for a role
+    pub const fn color(&self, role: Role) -> C {
+        match role {
+            Role::Primary => self.primary,
+            Role::Secondary => self.secondary,
+            Role::Background => self.background,
+            Role::Accent => self.accent,
+        }
+    }
+}
+
+/// Named themes with one of them active, like a light and a dark mode.
+///
+/// The active theme is switched atomically, so the set can live in a
+/// `static` and be switched from anywhere, even an interrupt handler. Images
+/// created with [`ThemeSet::image`] look up their colors when they're drawn,
+/// so they follow the switch without being recreated.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Role, Theme, ThemeSet};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// static THEMES: ThemeSet<Rgb565> = ThemeSet::new(&[
+///     ("day", Theme {
+///         primary: Rgb565::BLACK,
+///         secondary: Rgb565::CSS_DIM_GRAY,
+///         background: Rgb565::WHITE,
+///         accent: Rgb565::BLUE,
+///     }),
+///     ("night", Theme {
+///         primary: Rgb565::RED,
+///         secondary: Rgb565::CSS_DARK_RED,
+///         background: Rgb565::BLACK,
+///         accent: Rgb565::CSS_ORANGE,
+///     }),
+/// ])
+/// .unwrap();
+///
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+/// let gauge = THEMES.image(&raw, Point::zero(), Role::Primary).with_background(Role::Background);
+///
+/// assert!(THEMES.select("night"));
+/// let mut display: Display<Rgb565> = Display::default();
+/// gauge.draw(&mut display)?;
+/// assert_eq!(display.get_pixel(Point::zero()), Some(Rgb565::RED));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug)]
+pub struct ThemeSet<'a, C> {
+    themes: &'a [(&'a str, Theme<C>)],
+    active: AtomicUsize,
+}
+
+impl<'a, C> ThemeSet<'a, C>
+where
+    C: PixelColor,
+{
+    /// Create a set of named themes with the first one active
+    ///
+    /// Returns `None` if `themes` is empty.
+    pub const fn new(themes: &'a [(&'a str, Theme<C>)]) -> Option<Self> {
+        if themes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            themes,
+            active: AtomicUsize::new(0),
+        })
+    }
+
+    /// Returns the named themes
+    pub const fn themes(&self) -> &'a [(&'a str, Theme<C>)] {
+        self.themes
+    }
+
+    /// Activate the theme with the given name
+    ///
+    /// Returns `false` if there's no such theme.
+    pub fn select(&self, name: &str) -> bool {
+        match self.themes.iter().position(|(other, _)| *other == name) {
+            Some(index) => self.select_index(index),
+            None => false,
+        }
+    }
+
+    /// Activate the theme at `index`
+    ///
+    /// Returns `false` if the index is out of range.
+    pub fn select_index(&self, index: usize) -> bool {
+        if index >= self.themes.len() {
+            return false;
+        }
+        self.active.store(index, Ordering::Relaxed);
+        true
+    }
+
+    /// Returns the index of the active theme
+    pub fn active_index(&self) -> usize {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Returns the name of the active theme
+    pub fn active_name(&self) -> &'a str {
+        self.themes[self.active_index()].0
+    }
+
+    /// Returns the active theme
+    pub fn active(&self) -> &'a Theme<C> {
+        &self.themes[self.active_index()].1
+    }
+
+    /// Returns the color for a role in the active theme
+    pub fn color(&self, role: Role) -> C {
+        self.active().color(role)
+    }
+
+    /// Create an image that's drawn with the color of a role in the active theme
+    pub fn image<'b, T>(
+        &'b self,
+        image: &'b T,
+        position: Point,
+        role: Role,
+    ) -> ThemedImage<'b, T, C>
+    where
+        T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    {
+        ThemedImage {
+            image: Image::new(image, position, self.color(role)),
+            themes: self,
+            role,
+            background: None,
+        }
+    }
+}
+
+/// Image that takes its colors from the active theme of a [`ThemeSet`].
+#[derive(Debug, Clone, Copy)]
+pub struct ThemedImage<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    themes: &'a ThemeSet<'a, C>,
+    role: Role,
+    background: Option<Role>,
+}
+
+impl<'a, T, C> ThemedImage<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Instead of transparent background, draw the background with the color of a role
+    pub fn with_background(mut self, role: Role) -> Self {
+        self.background = Some(role);
+        self
+    }
+
+    /// Returns the image with the colors of the active theme
+    pub fn resolve(&self) -> Image<'a, T, C> {
+        let theme = self.themes.active();
+        let mut image = self.image;
+        image.color = theme.color(self.role);
+        image.background = self.background.map(|role| theme.color(role));
+        image
+    }
+}
+
+impl<T, C> Drawable for ThemedImage<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.resolve().draw(target)
+    }
+}
+
+impl<T, C> Dimensions for ThemedImage<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+impl<'a, T, C> Image<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create a new `Image` drawn with the color of a theme role
+    pub const fn themed(image: &'a T, position: Point, theme: &Theme<C>, role: Role) -> Self {
+        Self::new(image, position, theme.color(role))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        image::ImageRaw, mock_display::MockDisplay, pixelcolor::Rgb565, prelude::RgbColor,
+    };
+
+    #[test]
+    fn test_themed_image() {
+        let theme = Theme {
+            primary: Rgb565::WHITE,
+            secondary: Rgb565::BLUE,
+            background: Rgb565::BLACK,
+            accent: Rgb565::RED,
+        };
+        assert_eq!(theme.color(Role::Secondary), Rgb565::BLUE);
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let mut display = MockDisplay::new();
+        Image::themed(&raw, Point::zero(), &theme, Role::Primary)
+            .with_background(theme.background)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["W"]);
+    }
+
+    #[test]
+    fn test_theme_set_switch() {
+        let light = Theme {
+            primary: Rgb565::BLACK,
+            secondary: Rgb565::BLACK,
+            background: Rgb565::WHITE,
+            accent: Rgb565::BLUE,
+        };
+        let dark = Theme {
+            primary: Rgb565::WHITE,
+            background: Rgb565::BLACK,
+            ..light
+        };
+        let themes = [("light", light), ("dark", dark)];
+        let set = ThemeSet::new(&themes).unwrap();
+        assert!(ThemeSet::<Rgb565>::new(&[]).is_none());
+
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 2);
+        let image = set
+            .image(&raw, Point::zero(), Role::Primary)
+            .with_background(Role::Background);
+        assert_eq!(set.active_name(), "light");
+        assert_eq!(image.resolve().pixel_at(Point::zero()), Some(Rgb565::BLACK));
+
+        assert!(!set.select("sepia"));
+        assert!(set.select("dark"));
+        let mut display = MockDisplay::new();
+        image.draw(&mut display).unwrap();
+        display.assert_pattern(&["WK"]);
+    }
+}
@@ -0,0 +1,216 @@
+use embedded_graphics::{
+    Drawable, Pixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, Transform},
+    primitives::Rectangle,
+};
+
+/// Color cast for any binary drawable.
+///
+/// `Tinted` wraps a [`Drawable`] with `Color = BinaryColor`, like a `Text`
+/// using a mono font, and draws its `On` pixels in the given color. `Off`
+/// pixels are skipped unless a background color is set.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     mono_font::{MonoTextStyle, ascii::FONT_6X10},
+///     pixelcolor::{BinaryColor, Rgb565},
+///     prelude::*,
+///     text::Text,
+/// };
+/// use embedded_graphics_colorcast::Tinted;
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+///
+/// let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
+/// let text = Text::new("Hi", Point::new(0, 7), style);
+///
+/// Tinted::new(text, Rgb565::RED).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tinted<D, C> {
+    drawable: D,
+    color: C,
+    background: Option<C>,
+}
+
+impl<D, C> Tinted<D, C>
+where
+    D: Drawable<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Draw the `On` pixels of a drawable in the given color
+    pub const fn new(drawable: D, color: C) -> Self {
+        Self {
+            drawable,
+            color,
+            background: None,
+        }
+    }
+
+    /// Instead of skipping them, draw the `Off` pixels with given color
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Returns the wrapped drawable
+    pub fn into_inner(self) -> D {
+        self.drawable
+    }
+}
+
+impl<D, C> Drawable for Tinted<D, C>
+where
+    D: Drawable<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = D::Output;
+
+    fn draw<T>(&self, target: &mut T) -> Result<Self::Output, T::Error>
+    where
+        T: DrawTarget<Color = C>,
+    {
+        self.drawable.draw(&mut ColorCast {
+            target,
+            color: self.color,
+            background: self.background,
+        })
+    }
+}
+
+impl<D, C> Dimensions for Tinted<D, C>
+where
+    D: Dimensions,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.drawable.bounding_box()
+    }
+}
+
+impl<D, C> Transform for Tinted<D, C>
+where
+    D: Transform,
+    C: Copy,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            drawable: self.drawable.translate(by),
+            color: self.color,
+            background: self.background,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.drawable.translate_mut(by);
+        self
+    }
+}
+
+/// Draw target that maps binary colors to the colors of another target.
+struct ColorCast<'t, T, C> {
+    target: &'t mut T,
+    color: C,
+    background: Option<C>,
+}
+
+impl<T, C> ColorCast<'_, T, C>
+where
+    C: PixelColor,
+{
+    fn map(&self, color: BinaryColor) -> Option<C> {
+        if color.is_on() {
+            Some(self.color)
+        } else {
+            self.background
+        }
+    }
+}
+
+impl<T, C> Dimensions for ColorCast<'_, T, C>
+where
+    T: DrawTarget<Color = C>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<T, C> DrawTarget for ColorCast<'_, T, C>
+where
+    T: DrawTarget<Color = C>,
+    C: PixelColor,
+{
+    type Color = BinaryColor;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (color, background) = (self.color, self.background);
+        self.target
+            .draw_iter(pixels.into_iter().filter_map(|Pixel(point, c)| {
+                let c = if c.is_on() { color } else { background? };
+                Some(Pixel(point, c))
+            }))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        match self.map(color) {
+            Some(color) => self.target.fill_solid(area, color),
+            None => Ok(()),
+        }
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        match self.map(color) {
+            Some(color) => self.target.clear(color),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::Rgb565,
+        prelude::{Primitive, RgbColor, Size},
+        primitives::{PrimitiveStyle, PrimitiveStyleBuilder},
+    };
+
+    #[test]
+    fn test_tinted_primitive() {
+        let rect = Rectangle::new(Point::new(1, 0), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1));
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        Tinted::new(rect, Rgb565::RED).draw(&mut display).unwrap();
+        display.assert_pattern(&[" RR", " RR"]);
+    }
+
+    #[test]
+    fn test_tinted_background() {
+        let rect = Rectangle::new(Point::zero(), Size::new(3, 1)).into_styled(
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(1)
+                .fill_color(BinaryColor::Off)
+                .build(),
+        );
+        let pixel = Pixel(Point::new(1, 1), BinaryColor::Off);
+
+        let mut display = MockDisplay::<Rgb565>::new();
+        Tinted::new(rect, Rgb565::RED).draw(&mut display).unwrap();
+        Tinted::new(pixel, Rgb565::RED)
+            .with_background(Rgb565::BLUE)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["RRR", " B "]);
+    }
+}
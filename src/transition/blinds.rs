@@ -0,0 +1,161 @@
+use super::{Edge, draw_revealed};
+use crate::{Image, easing::Easing, fixed::Fixed};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Reveals an image in bands that open like window blinds.
+///
+/// The image is split into bands of equal size, which all open from the same
+/// edge at once. Bands open from the top or bottom are horizontal, bands open
+/// from the left or right are vertical. Only the revealed part of the image
+/// is drawn, so drawing it over the previous content with growing progress
+/// replaces that content band by band.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{
+///     Image,
+///     easing::Easing,
+///     transition::{Blinds, Edge},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+/// let image = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+///
+/// let mut blinds = Blinds::new(image, 4, Edge::Top);
+/// # display.set_allow_overdraw(true);
+/// for step in 0..=4 {
+///     blinds.set_progress(Easing::Linear.at(step, 4));
+///     blinds.draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Blinds<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    band: u32,
+    edge: Edge,
+    easing: Easing,
+    progress: Fixed,
+}
+
+impl<'a, T, C> Blinds<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Reveal an image in bands of `band` pixels, opening from `edge`
+    ///
+    /// A band size of zero is treated as one.
+    pub fn new(image: Image<'a, T, C>, band: u32, edge: Edge) -> Self {
+        Self {
+            image,
+            band: band.max(1),
+            edge,
+            easing: Easing::Linear,
+            progress: Fixed::ZERO,
+        }
+    }
+
+    /// Shape the opening with an easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the image
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the current progress
+    pub const fn progress(&self) -> Fixed {
+        self.progress
+    }
+
+    /// Set the progress, from closed at zero to fully open at one
+    pub fn set_progress(&mut self, progress: Fixed) {
+        self.progress = progress;
+    }
+
+    /// Returns how many pixels of every band are open
+    pub fn open(&self) -> u32 {
+        (self.easing.apply(self.progress) * self.band as i32).round() as u32
+    }
+
+    /// Returns `true` if the pixel at `point`, relative to the image, is revealed
+    fn is_revealed(&self, point: Point, open: u32) -> bool {
+        let offset = match self.edge {
+            Edge::Top | Edge::Bottom => point.y as u32 % self.band,
+            Edge::Left | Edge::Right => point.x as u32 % self.band,
+        };
+        match self.edge {
+            Edge::Top | Edge::Left => offset < open,
+            Edge::Bottom | Edge::Right => self.band - 1 - offset < open,
+        }
+    }
+}
+
+impl<T, C> Drawable for Blinds<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let open = self.open();
+        draw_revealed(&self.image, target, |point| self.is_revealed(point, open))
+    }
+}
+
+impl<T, C> Dimensions for Blinds<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    #[test]
+    fn test_blinds_reveal_bands() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xf0; 4], 4);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let half = Fixed::from_ratio(1, 2).unwrap();
+
+        let mut blinds = Blinds::new(image, 2, Edge::Top);
+        blinds.set_progress(half);
+        let mut display = MockDisplay::new();
+        blinds.draw(&mut display).unwrap();
+        display.assert_pattern(&["####", "    ", "####"]);
+
+        let mut blinds = Blinds::new(image, 4, Edge::Right);
+        blinds.set_progress(half);
+        let mut display = MockDisplay::new();
+        blinds.draw(&mut display).unwrap();
+        display.assert_pattern(&["  ##", "  ##", "  ##", "  ##"]);
+    }
+}
@@ -0,0 +1,181 @@
+use super::draw_revealed;
+use crate::{Image, easing::Easing, fixed::Fixed};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Reveals an image like the hand of a clock sweeping around a center.
+///
+/// The sweep starts at twelve o'clock and turns clockwise. By default it
+/// turns around the center of the image, which suits round displays and
+/// watch faces. Only the revealed part of the image is drawn.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, easing::Easing, transition::ClockWipe};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+/// let image = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+///
+/// let mut wipe = ClockWipe::new(image).with_easing(Easing::InOutQuad);
+/// # display.set_allow_overdraw(true);
+/// for step in 0..=12 {
+///     wipe.set_progress(Easing::Linear.at(step, 12));
+///     wipe.draw(&mut display)?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ClockWipe<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    /// Center in half pixels relative to the image, so it can sit between pixels
+    center: Point,
+    easing: Easing,
+    progress: Fixed,
+}
+
+impl<'a, T, C> ClockWipe<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Reveal an image by sweeping around its center
+    pub fn new(image: Image<'a, T, C>) -> Self {
+        let size = image.image.size();
+        Self {
+            image,
+            center: Point::new(size.width as i32, size.height as i32),
+            easing: Easing::Linear,
+            progress: Fixed::ZERO,
+        }
+    }
+
+    /// Sweep around the pixel at `center`, relative to the image
+    pub fn with_center(mut self, center: Point) -> Self {
+        self.center = center * 2 + Point::new(1, 1);
+        self
+    }
+
+    /// Shape the sweep with an easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the image
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the current progress
+    pub const fn progress(&self) -> Fixed {
+        self.progress
+    }
+
+    /// Set the progress, from hidden at zero to a full turn at one
+    pub fn set_progress(&mut self, progress: Fixed) {
+        self.progress = progress;
+    }
+
+    /// Returns the angle swept so far in whole degrees
+    pub fn angle(&self) -> i32 {
+        (self.easing.apply(self.progress) * 360).round()
+    }
+
+    /// Returns `true` if the pixel at `point`, relative to the image, is revealed
+    fn is_revealed(&self, point: Point, angle: i32, hand: (Fixed, Fixed)) -> bool {
+        if angle >= 360 {
+            return true;
+        }
+        if angle <= 0 {
+            return false;
+        }
+
+        // Offset from the center with y pointing up, in half pixels.
+        let x = point.x * 2 + 1 - self.center.x;
+        let y = self.center.y - (point.y * 2 + 1);
+        // The sign of the cross product of hand and point tells if the
+        // point lies before the hand, within half a turn of each other.
+        let (sin, cos) = hand;
+        let before = sin * y - cos * x > Fixed::ZERO;
+
+        let first_half = x > 0 || (x == 0 && y >= 0);
+        if first_half {
+            angle >= 180 || before
+        } else {
+            angle > 180 && before
+        }
+    }
+}
+
+impl<T, C> Drawable for ClockWipe<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let angle = self.angle();
+        let hand = Fixed::sin_cos(angle);
+        draw_revealed(&self.image, target, |point| {
+            self.is_revealed(point, angle, hand)
+        })
+    }
+}
+
+impl<T, C> Dimensions for ClockWipe<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    fn draw_at(progress: Fixed, expected: &[&str]) {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xf0; 4], 4);
+        let mut wipe = ClockWipe::new(Image::new(&raw, Point::zero(), BinaryColor::On));
+        wipe.set_progress(progress);
+
+        let mut display = MockDisplay::new();
+        wipe.draw(&mut display).unwrap();
+        display.assert_pattern(expected);
+    }
+
+    #[test]
+    fn test_clock_wipe_quarters() {
+        draw_at(Fixed::ZERO, &[]);
+        draw_at(Fixed::from_ratio(1, 4).unwrap(), &["  ##", "  ##"]);
+        draw_at(
+            Fixed::from_ratio(1, 2).unwrap(),
+            &["  ##", "  ##", "  ##", "  ##"],
+        );
+        draw_at(
+            Fixed::from_ratio(3, 4).unwrap(),
+            &["  ##", "  ##", "####", "####"],
+        );
+        draw_at(Fixed::ONE, &["####", "####", "####", "####"]);
+    }
+}
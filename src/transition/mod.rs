@@ -0,0 +1,91 @@
+//! Animated transitions for images.
+//!
+//! Transitions are driven by a [`Fixed`] progress from zero to one, which can
+//! be shaped with an [`Easing`](crate::easing::Easing) curve. [`Tween`] is
+//! driven by the elapsed time instead.
+
+use crate::{Image, fixed::Fixed};
+use embedded_graphics::{
+    Drawable, Pixel,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, PointsIter},
+    primitives::Rectangle,
+};
+
+mod blinds;
+mod clock;
+mod slide;
+mod tween;
+mod typewriter;
+
+pub use self::blinds::Blinds;
+pub use self::clock::ClockWipe;
+pub use self::slide::{Edge, Slide};
+pub use self::tween::Tween;
+pub use self::typewriter::Typewriter;
+
+/// Returns the point at `t` on the way from `from` to `to`
+fn lerp(from: Point, to: Point, t: Fixed) -> Point {
+    let delta = to - from;
+    from + Point::new(
+        (Fixed::from_int(delta.x) * t).round(),
+        (Fixed::from_int(delta.y) * t).round(),
+    )
+}
+
+/// Draw an image that moved away from `before`, erasing the uncovered part
+///
+/// Images without a background of their own are drawn with `background`, so
+/// no parts of the previous position remain visible.
+fn redraw_moved<T, C, D>(
+    image: &Image<'_, T, C>,
+    before: Rectangle,
+    target: &mut D,
+    background: C,
+) -> Result<(), D::Error>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+{
+    let after = image.bounding_box();
+    let uncovered = before.intersection(&target.bounding_box());
+    target.draw_iter(
+        uncovered
+            .points()
+            .filter(|&point| !after.contains(point))
+            .map(|point| Pixel(point, background)),
+    )?;
+
+    let mut image = *image;
+    if image.background.is_none() {
+        image.background = Some(background);
+    }
+    image.draw(target)
+}
+
+/// Draw the pixels of an image for which `revealed` returns `true`
+///
+/// `revealed` is called with points relative to the image.
+fn draw_revealed<T, C, D, F>(
+    image: &Image<'_, T, C>,
+    target: &mut D,
+    revealed: F,
+) -> Result<(), D::Error>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+    F: Fn(Point) -> bool,
+{
+    let area = image.bounding_box().intersection(&target.bounding_box());
+    target.draw_iter(area.points().filter_map(|point| {
+        let local = point - image.position;
+        if !revealed(local) {
+            return None;
+        }
+        image.pixel_at(local).map(|color| Pixel(point, color))
+    }))
+}
@@ -0,0 +1,221 @@
+use super::{lerp, redraw_moved};
+use crate::{Image, easing::Easing, fixed::Fixed, geometry};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Transform},
+    primitives::Rectangle,
+};
+
+/// Edge of the screen an image slides in from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// Slide in from the left
+    Left,
+    /// Slide in from the right
+    Right,
+    /// Slide in from the top
+    Top,
+    /// Slide in from the bottom
+    Bottom,
+}
+
+/// Moves an image from outside the screen to its position.
+///
+/// At a progress of zero the image is just outside of the screen, at a
+/// progress of one it's at its own position. Decreasing the progress slides
+/// it out again. [`Slide::update`] erases the part of the previous position
+/// that isn't covered anymore, so only the changed area is drawn.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{
+///     Image,
+///     easing::Easing,
+///     fixed::Fixed,
+///     transition::{Edge, Slide},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+/// let image = Image::new(&raw, Point::new(8, 8), Rgb565::WHITE);
+///
+/// let mut slide = Slide::new(image, display.bounding_box(), Edge::Left)
+///     .with_easing(Easing::OutCubic);
+/// # display.set_allow_overdraw(true);
+/// for step in 0..=4 {
+///     slide.update(&mut display, Easing::Linear.at(step, 4), Rgb565::BLACK)?;
+/// }
+/// assert_eq!(slide.progress(), Fixed::ONE);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Slide<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    rest: Point,
+    start: Point,
+    easing: Easing,
+    progress: Fixed,
+}
+
+impl<'a, T, C> Slide<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Slide an image in from an edge of the screen
+    ///
+    /// The image starts just outside `screen` and ends at its own position.
+    pub fn new(image: Image<'a, T, C>, screen: Rectangle, edge: Edge) -> Self {
+        let area = image.bounding_box();
+        let size = area.size;
+        let rest = area.top_left;
+        let start = match edge {
+            Edge::Left => Point::new(screen.top_left.x - size.width as i32, rest.y),
+            Edge::Right => Point::new(screen.top_left.x + screen.size.width as i32, rest.y),
+            Edge::Top => Point::new(rest.x, screen.top_left.y - size.height as i32),
+            Edge::Bottom => Point::new(rest.x, screen.top_left.y + screen.size.height as i32),
+        };
+
+        Self {
+            image: image.translate(start - rest),
+            rest,
+            start,
+            easing: Easing::Linear,
+            progress: Fixed::ZERO,
+        }
+    }
+
+    /// Shape the motion with an easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the current progress
+    pub const fn progress(&self) -> Fixed {
+        self.progress
+    }
+
+    /// Returns the image at its current position
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the position of the image at the given progress
+    pub fn position_at(&self, progress: Fixed) -> Point {
+        lerp(self.start, self.rest, self.easing.apply(progress))
+    }
+
+    /// Move the image to the given progress and return the area that changed
+    ///
+    /// This only updates the position, the caller has to redraw the area.
+    pub fn set_progress(&mut self, progress: Fixed) -> Rectangle {
+        let before = self.image.bounding_box();
+        self.progress = progress;
+        let offset = self.position_at(progress) - before.top_left;
+        self.image.translate_mut(offset);
+
+        if offset == Point::zero() {
+            return Rectangle::zero();
+        }
+        geometry::union(&before, &self.image.bounding_box())
+    }
+
+    /// Move the image to the given progress and draw it
+    ///
+    /// The part of the previous position that isn't covered by the image
+    /// anymore is filled with `background`. Images without a background of
+    /// their own are drawn with `background`, so no parts of the previous
+    /// position remain visible. Returns the area that changed, clipped to the
+    /// target.
+    pub fn update<D>(
+        &mut self,
+        target: &mut D,
+        progress: Fixed,
+        background: C,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let before = self.image.bounding_box();
+        let changed = self.set_progress(progress);
+        redraw_moved(&self.image, before, target, background)?;
+        Ok(changed.intersection(&target.bounding_box()))
+    }
+}
+
+impl<T, C> Drawable for Slide<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.image.draw(target)
+    }
+}
+
+impl<T, C> Dimensions for Slide<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay, prelude::Size};
+
+    #[test]
+    fn test_slide_positions() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xf0], 4);
+        let image = Image::new(&raw, Point::new(2, 3), BinaryColor::On);
+        let screen = Rectangle::new(Point::zero(), Size::new(16, 16));
+
+        let slide = Slide::new(image, screen, Edge::Left);
+        assert_eq!(slide.image().bounding_box().top_left, Point::new(-4, 3));
+        assert_eq!(slide.position_at(Fixed::ONE), Point::new(2, 3));
+        let half = Fixed::from_ratio(1, 2).unwrap();
+        assert_eq!(slide.position_at(half), Point::new(-1, 3));
+
+        let slide = Slide::new(image, screen, Edge::Bottom);
+        assert_eq!(slide.position_at(Fixed::ZERO), Point::new(2, 16));
+    }
+
+    #[test]
+    fn test_slide_update_erases() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1100_0000], 2);
+        let image = Image::new(&raw, Point::new(2, 0), BinaryColor::On);
+        let screen = Rectangle::new(Point::zero(), Size::new(4, 1));
+        let mut slide = Slide::new(image, screen, Edge::Left);
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let half = Fixed::from_ratio(1, 2).unwrap();
+        slide.update(&mut display, half, BinaryColor::Off).unwrap();
+        display.assert_pattern(&["##"]);
+
+        let changed = slide
+            .update(&mut display, Fixed::ONE, BinaryColor::Off)
+            .unwrap();
+        display.assert_pattern(&["..##"]);
+        assert_eq!(changed, Rectangle::new(Point::zero(), Size::new(4, 1)));
+    }
+}
@@ -0,0 +1,271 @@
+use super::{lerp, redraw_moved};
+use crate::{Image, easing::Easing, geometry};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Transform},
+    primitives::Rectangle,
+};
+
+/// Moves an image between two points over a fixed duration.
+///
+/// The tween is advanced with the elapsed time in milliseconds. Every update
+/// returns the area that changed, which can be passed on to a
+/// [`FrameScheduler`](crate::FrameScheduler), or
+/// [`Tween::update_and_draw`] erases the previous position right away.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, easing::Easing, transition::Tween};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xf0; 4], 4);
+/// let image = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+///
+/// let mut tween = Tween::new(image, Point::new(40, 20), 500).with_easing(Easing::InOutQuad);
+/// # display.set_allow_overdraw(true);
+/// while !tween.is_done() {
+///     tween.update_and_draw(&mut display, 50, Rgb565::BLACK)?;
+/// }
+/// assert_eq!(tween.position(), Point::new(40, 20));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tween<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    /// Endpoints and duration of the whole tween
+    start: Point,
+    end: Point,
+    total: u32,
+    reversed: bool,
+    /// Leg that is currently running, shortened by [`Tween::reverse`]
+    from: Point,
+    to: Point,
+    duration: u32,
+    elapsed: u32,
+    easing: Easing,
+}
+
+impl<'a, T, C> Tween<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Move an image from its position to `to` in `duration` milliseconds
+    pub fn new(image: Image<'a, T, C>, to: Point, duration: u32) -> Self {
+        Self {
+            start: image.position,
+            end: to,
+            total: duration,
+            reversed: false,
+            from: image.position,
+            image,
+            to,
+            duration,
+            elapsed: 0,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Shape the motion with an easing curve
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the image at its current position
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the current position of the image
+    pub const fn position(&self) -> Point {
+        self.image.position
+    }
+
+    /// Returns the time since the start in milliseconds
+    pub const fn elapsed(&self) -> u32 {
+        self.elapsed
+    }
+
+    /// Returns `true` if the image reached its destination
+    pub const fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Move the image back to the start
+    ///
+    /// This also undoes [`Tween::reverse`]. Returns the area that changed.
+    pub fn restart(&mut self) -> Rectangle {
+        self.reversed = false;
+        self.from = self.start;
+        self.to = self.end;
+        self.duration = self.total;
+        self.seek(0)
+    }
+
+    /// Turn around and head for the other endpoint, beginning at the current position
+    ///
+    /// This turns an unfinished tween around without jumping. The way back
+    /// takes as long as the tween took to get here, and the easing curve
+    /// starts over from the current position. Reversing again heads for the
+    /// original destination.
+    pub fn reverse(&mut self) {
+        // Time the whole tween would need from its start to get here.
+        let progress = if self.reversed {
+            self.duration - self.elapsed
+        } else {
+            self.total - self.duration + self.elapsed
+        };
+
+        self.reversed = !self.reversed;
+        self.from = self.image.position;
+        (self.to, self.duration) = if self.reversed {
+            (self.start, progress)
+        } else {
+            (self.end, self.total - progress)
+        };
+        self.elapsed = 0;
+    }
+
+    /// Advance by `dt` milliseconds and return the area that changed
+    ///
+    /// This only updates the position, the caller has to redraw the area.
+    pub fn update(&mut self, dt: u32) -> Rectangle {
+        self.seek(self.elapsed.saturating_add(dt))
+    }
+
+    /// Advance by `dt` milliseconds and draw the image
+    ///
+    /// The part of the previous position that isn't covered by the image
+    /// anymore is filled with `background`. Returns the area that changed,
+    /// clipped to the target.
+    pub fn update_and_draw<D>(
+        &mut self,
+        target: &mut D,
+        dt: u32,
+        background: C,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let before = self.image.bounding_box();
+        let changed = self.update(dt);
+        redraw_moved(&self.image, before, target, background)?;
+        Ok(changed.intersection(&target.bounding_box()))
+    }
+
+    fn seek(&mut self, elapsed: u32) -> Rectangle {
+        self.elapsed = elapsed.min(self.duration);
+        let t = self.easing.at(self.elapsed, self.duration);
+
+        let before = self.image.bounding_box();
+        let offset = lerp(self.from, self.to, t) - self.image.position;
+        if offset == Point::zero() {
+            return Rectangle::zero();
+        }
+        self.image.translate_mut(offset);
+        geometry::union(&before, &self.image.bounding_box())
+    }
+}
+
+impl<T, C> Drawable for Tween<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.image.draw(target)
+    }
+}
+
+impl<T, C> Dimensions for Tween<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, prelude::Size};
+
+    #[test]
+    fn test_tween_reports_changed_area() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xc0, 0xc0], 2);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let mut tween = Tween::new(image, Point::new(8, 0), 100);
+
+        assert_eq!(
+            tween.update(50),
+            Rectangle::new(Point::zero(), Size::new(6, 2))
+        );
+        assert_eq!(tween.position(), Point::new(4, 0));
+        assert!(tween.update(0).is_zero_sized());
+
+        tween.reverse();
+        assert_eq!(tween.elapsed(), 0);
+        tween.update(100);
+        assert!(tween.is_done());
+        assert_eq!(tween.position(), Point::zero());
+    }
+
+    #[test]
+    fn test_reverse_keeps_position_with_easing() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let mut tween = Tween::new(image, Point::new(100, 0), 100).with_easing(Easing::InQuad);
+
+        tween.update(30);
+        assert_eq!(tween.position(), Point::new(9, 0));
+        tween.reverse();
+        assert!(tween.update(0).is_zero_sized());
+        assert_eq!(tween.position(), Point::new(9, 0));
+
+        tween.update(15);
+        assert_eq!(tween.position(), Point::new(7, 0));
+        tween.update(15);
+        assert_eq!(tween.position(), Point::zero());
+    }
+
+    #[test]
+    fn test_reverse_twice() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0x80], 1);
+        let image = Image::new(&raw, Point::zero(), BinaryColor::On);
+        let mut tween = Tween::new(image, Point::new(100, 0), 100);
+
+        tween.update(50);
+        tween.reverse();
+        tween.update(20);
+        assert_eq!(tween.position(), Point::new(30, 0));
+
+        tween.reverse();
+        tween.update(69);
+        assert!(!tween.is_done());
+        tween.update(1);
+        assert!(tween.is_done());
+        assert_eq!(tween.position(), Point::new(100, 0));
+
+        tween.restart();
+        assert_eq!(tween.position(), Point::zero());
+        tween.update(100);
+        assert_eq!(tween.position(), Point::new(100, 0));
+    }
+}
@@ -0,0 +1,158 @@
+use super::draw_revealed;
+use crate::Image;
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Reveals an image column by column, left to right.
+///
+/// Every [`Typewriter::step`] reveals the next columns and returns their
+/// area, so only the new columns have to be drawn. This is the retro
+/// terminal look, where text appears as if it was typed.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, transition::Typewriter};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let raw = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+/// let image = Image::new(&raw, Point::zero(), Rgb565::WHITE);
+///
+/// let mut typewriter = Typewriter::new(image).with_speed(2);
+/// while !typewriter.is_done() {
+///     let area = typewriter.step();
+///     typewriter.draw(&mut display.clipped(&area))?;
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Typewriter<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    image: Image<'a, T, C>,
+    speed: u32,
+    revealed: u32,
+}
+
+impl<'a, T, C> Typewriter<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Reveal an image one column per step, starting with no columns
+    pub const fn new(image: Image<'a, T, C>) -> Self {
+        Self {
+            image,
+            speed: 1,
+            revealed: 0,
+        }
+    }
+
+    /// Reveal `columns` columns per step
+    ///
+    /// A speed of zero is treated as one.
+    pub const fn with_speed(mut self, columns: u32) -> Self {
+        self.speed = if columns == 0 { 1 } else { columns };
+        self
+    }
+
+    /// Returns the image
+    pub const fn image(&self) -> &Image<'a, T, C> {
+        &self.image
+    }
+
+    /// Returns the number of revealed columns
+    pub const fn revealed(&self) -> u32 {
+        self.revealed
+    }
+
+    /// Returns `true` if all columns are revealed
+    pub fn is_done(&self) -> bool {
+        self.revealed >= self.image.image.size().width
+    }
+
+    /// Set the number of revealed columns
+    ///
+    /// The number is capped to the width of the image.
+    pub fn set_revealed(&mut self, columns: u32) {
+        self.revealed = columns.min(self.image.image.size().width);
+    }
+
+    /// Reveal the next columns and return their area
+    ///
+    /// Returns a zero sized area if all columns are revealed already.
+    pub fn step(&mut self) -> Rectangle {
+        let before = self.revealed;
+        self.set_revealed(before.saturating_add(self.speed));
+
+        let height = self.image.image.size().height;
+        Rectangle::new(
+            self.image.position + Point::new(before as i32, 0),
+            Size::new(self.revealed - before, height),
+        )
+    }
+}
+
+impl<T, C> Drawable for Typewriter<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_revealed(&self.image, target, |point| {
+            (point.x as u32) < self.revealed
+        })
+    }
+}
+
+impl<T, C> Dimensions for Typewriter<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.image.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    #[test]
+    fn test_typewriter_steps() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0b1111_1000], 5);
+        let image = Image::new(&raw, Point::new(1, 0), BinaryColor::On);
+        let mut typewriter = Typewriter::new(image).with_speed(3);
+
+        assert_eq!(
+            typewriter.step(),
+            Rectangle::new(Point::new(1, 0), Size::new(3, 1))
+        );
+        let mut display = MockDisplay::new();
+        typewriter.draw(&mut display).unwrap();
+        display.assert_pattern(&[" ###"]);
+
+        assert_eq!(
+            typewriter.step(),
+            Rectangle::new(Point::new(4, 0), Size::new(2, 1))
+        );
+        assert!(typewriter.is_done());
+        assert!(typewriter.step().is_zero_sized());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::fixed::{Fixed, Matrix};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+/// Binary source with an affine transformation applied.
+///
+/// Scaling, rotation, shearing and translation can be combined in any order.
+/// Pixels are sampled by mapping the center of each output pixel back to the
+/// source. The view covers the bounding box of the transformed image, with
+/// its top left corner at [`Affine::offset`] in transformed coordinates.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::Affine};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let arrow = bitmap!["##.", "#..", "..."];
+///
+/// let rotated = Affine::new(&arrow).rotate(90).scale(2, 1).unwrap();
+/// Image::new(&rotated, Point::zero(), BinaryColor::On).draw(&mut display)?;
+/// assert_eq!(rotated.size(), Size::new(6, 6));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Affine<'a, T> {
+    source: &'a T,
+    matrix: Matrix,
+    inverse: Option<Matrix>,
+    bounds: Rectangle,
+}
+
+impl<'a, T> Affine<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Wrap a source without transforming it
+    pub fn new(source: &'a T) -> Self {
+        Self {
+            source,
+            matrix: Matrix::IDENTITY,
+            inverse: Some(Matrix::IDENTITY),
+            bounds: Rectangle::new(Point::zero(), source.size()),
+        }
+    }
+
+    /// Wrap a source with a precomputed transformation
+    pub fn with_matrix(source: &'a T, matrix: Matrix) -> Self {
+        Self::new(source).then(matrix)
+    }
+
+    /// Returns the combined transformation
+    pub const fn matrix(&self) -> Matrix {
+        self.matrix
+    }
+
+    /// Scale by `numerator / denominator`
+    ///
+    /// Returns `None` if the denominator is zero or the ratio is out of range.
+    pub fn scale(self, numerator: i32, denominator: i32) -> Option<Self> {
+        let factor = Fixed::from_ratio(numerator, denominator)?;
+        Some(self.then(Matrix::scale(factor, factor)))
+    }
+
+    /// Rotate clockwise by whole degrees
+    pub fn rotate(self, degrees: i32) -> Self {
+        self.then(Matrix::rotation(degrees))
+    }
+
+    /// Rotate clockwise by an angle in radians
+    #[cfg(any(feature = "libm", feature = "micromath"))]
+    pub fn rotate_radians(self, radians: f32) -> Self {
+        self.then(Matrix::rotation_radians(radians))
+    }
+
+    /// Shift rows horizontally by `numerator / denominator` pixels per row
+    ///
+    /// Returns `None` if the denominator is zero or the ratio is out of range.
+    pub fn shear(self, numerator: i32, denominator: i32) -> Option<Self> {
+        let factor = Fixed::from_ratio(numerator, denominator)?;
+        Some(self.then(Matrix::shear(factor, Fixed::from_int(0))))
+    }
+
+    /// Move by `by` pixels
+    ///
+    /// This only changes [`Affine::offset`], the view itself always starts at
+    /// the top left corner of the transformed image.
+    pub fn translate(self, by: Point) -> Self {
+        self.then(Matrix::translation(
+            Fixed::from_int(by.x),
+            Fixed::from_int(by.y),
+        ))
+    }
+
+    /// Returns the position of the top left corner of the view in transformed coordinates
+    ///
+    /// Draw the view at `position + offset` to place the transformed source
+    /// origin at `position`.
+    pub const fn offset(&self) -> Point {
+        self.bounds.top_left
+    }
+
+    fn then(mut self, step: Matrix) -> Self {
+        self.matrix = self.matrix.then(&step);
+        self.inverse = self.matrix.inverse();
+        self.bounds = self.transformed_bounds();
+        self
+    }
+
+    fn transformed_bounds(&self) -> Rectangle {
+        let size = self.source.size();
+        let (w, h) = (
+            Fixed::from_int(size.width as i32),
+            Fixed::from_int(size.height as i32),
+        );
+        let zero = Fixed::from_int(0);
+
+        let corners =
+            [(zero, zero), (w, zero), (zero, h), (w, h)].map(|(x, y)| self.matrix.apply(x, y));
+        let (mut min, mut max) = (
+            Point::new(i32::MAX, i32::MAX),
+            Point::new(i32::MIN, i32::MIN),
+        );
+        for (x, y) in corners {
+            min = min.component_min(Point::new(x.floor(), y.floor()));
+            max = max.component_max(Point::new(x.ceil(), y.ceil()));
+        }
+
+        let size = max - min;
+        Rectangle::new(min, Size::new(size.x as u32, size.y as u32))
+    }
+}
+
+impl<T> OriginDimensions for Affine<'_, T> {
+    fn size(&self) -> Size {
+        if self.inverse.is_none() {
+            return Size::zero();
+        }
+        self.bounds.size
+    }
+}
+
+impl<T> GetPixel for Affine<'_, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let inverse = self.inverse?;
+        if !Rectangle::new(Point::zero(), self.bounds.size).contains(p) {
+            return None;
+        }
+
+        let half = Fixed::from_ratio(1, 2)?;
+        let p = p + self.bounds.top_left;
+        let (x, y) = inverse.apply(Fixed::from_int(p.x) + half, Fixed::from_int(p.y) + half);
+        let source = Point::new(x.floor(), y.floor());
+        Some(self.source.pixel(source).unwrap_or(BinaryColor::Off))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_affine_rotate() {
+        let source = AsciiImage::from_rows(&["##", "#."]).unwrap();
+        let rotated = Affine::new(&source).rotate(90);
+
+        assert_eq!(rotated.size(), Size::new(2, 2));
+        assert_eq!(rotated.offset(), Point::new(-2, 0));
+        assert_eq!(rotated.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(rotated.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(rotated.pixel(Point::new(0, 1)), Some(BinaryColor::Off));
+        assert_eq!(rotated.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn test_affine_singular() {
+        let source = AsciiImage::from_rows(&["##"]).unwrap();
+        let flat = Affine::new(&source).scale(0, 1).unwrap();
+        assert_eq!(flat.size(), Size::zero());
+        assert_eq!(flat.pixel(Point::zero()), None);
+
+        assert!(Affine::new(&source).scale(1, 0).is_none());
+        assert!(Affine::new(&source).shear(1, 0).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, Point, Size, Transform},
+    primitives::Rectangle,
+};
+
+/// Rectangular part of a binary image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cropped<'a, T> {
+    source: &'a T,
+    area: Rectangle,
+}
+
+impl<'a, T> Cropped<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Crop `source` to `area`
+    ///
+    /// The area is clipped to the bounds of the source image.
+    pub fn new(source: &'a T, area: &Rectangle) -> Self {
+        Self {
+            source,
+            area: area.intersection(&source.bounding_box()),
+        }
+    }
+
+    /// Crop this view further
+    ///
+    /// `area` is relative to the top left corner of this view.
+    pub fn crop(&self, area: &Rectangle) -> Self {
+        let area = area.intersection(&self.bounding_box());
+        Self {
+            source: self.source,
+            area: area.translate(self.area.top_left),
+        }
+    }
+
+    /// Returns the cropped area in source coordinates
+    pub const fn area(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<T> OriginDimensions for Cropped<'_, T> {
+    fn size(&self) -> Size {
+        self.area.size
+    }
+}
+
+impl<T> GetPixel for Cropped<'_, T>
+where
+    T: GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let p = p + self.area.top_left;
+        if self.area.contains(p) {
+            self.source.pixel(p)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_crop_nested() {
+        let source = AsciiImage::from_rows(&["....", ".#..", "..#."]).unwrap();
+        let cropped = Cropped::new(
+            &source,
+            &Rectangle::new(Point::new(1, 1), Size::new(10, 10)),
+        );
+        assert_eq!(cropped.size(), Size::new(3, 2));
+        assert_eq!(cropped.pixel(Point::zero()), Some(BinaryColor::On));
+
+        let nested = cropped.crop(&Rectangle::new(Point::new(1, 1), Size::new(1, 1)));
+        assert_eq!(
+            nested.area(),
+            Rectangle::new(Point::new(2, 2), Size::new(1, 1))
+        );
+        assert_eq!(nested.pixel(Point::zero()), Some(BinaryColor::On));
+        assert_eq!(nested.pixel(Point::new(1, 0)), None);
+    }
+}
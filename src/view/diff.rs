@@ -0,0 +1,88 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Pixels that differ between two sources.
+///
+/// Unlike [`Xor`](super::Xor), a pixel that only exists in one of the sources
+/// always counts as different, so a size change is highlighted too. Draw the
+/// view in a highlight color on top of the new frame to see what changed.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, view::Diff};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+///
+/// let old: ImageRaw<BinaryColor> = ImageRaw::new(&[0b1100_0000], 3);
+/// let new: ImageRaw<BinaryColor> = ImageRaw::new(&[0b0100_0000], 4);
+///
+/// Image::new(&Diff::new(&old, &new), Point::zero(), Rgb565::RED).draw(&mut display)?;
+/// display.assert_pattern(&["R  R"]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Diff<'a, A, B> {
+    old: &'a A,
+    new: &'a B,
+}
+
+impl<'a, A, B> Diff<'a, A, B>
+where
+    A: OriginDimensions + GetPixel<Color = BinaryColor>,
+    B: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Compare two sources aligned at their top left corners
+    pub const fn new(old: &'a A, new: &'a B) -> Self {
+        Self { old, new }
+    }
+}
+
+impl<A, B> OriginDimensions for Diff<'_, A, B>
+where
+    A: OriginDimensions,
+    B: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.old.size().component_max(self.new.size())
+    }
+}
+
+impl<A, B> GetPixel for Diff<'_, A, B>
+where
+    A: OriginDimensions + GetPixel<Color = BinaryColor>,
+    B: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let (old, new) = (self.old.pixel(p), self.new.pixel(p));
+        if old.is_none() && new.is_none() {
+            return None;
+        }
+        Some((old != new).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_diff_missing_pixels() {
+        let old = AsciiImage::from_rows(&["#."]).unwrap();
+        let new = AsciiImage::from_rows(&["##", ".."]).unwrap();
+        let diff = Diff::new(&old, &new);
+
+        assert_eq!(diff.size(), Size::new(2, 2));
+        assert_eq!(diff.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(diff.pixel(Point::new(1, 0)), Some(BinaryColor::On));
+        assert_eq!(diff.pixel(Point::new(0, 1)), Some(BinaryColor::On));
+        assert_eq!(diff.pixel(Point::new(2, 0)), None);
+    }
+}
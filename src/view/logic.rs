@@ -0,0 +1,159 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Color of a pixel, with pixels outside of the source being `Off`.
+fn bit<T: GetPixel<Color = BinaryColor>>(source: &T, p: Point) -> bool {
+    source.pixel(p) == Some(BinaryColor::On)
+}
+
+macro_rules! binary_view {
+    ($(#[$meta:meta])* $name:ident, $op:tt) => {
+        $(#[$meta])*
+        ///
+        /// The view covers both sources, pixels outside of one of them are
+        /// treated as `Off`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name<'a, A, B> {
+            a: &'a A,
+            b: &'a B,
+        }
+
+        impl<'a, A, B> $name<'a, A, B>
+        where
+            A: OriginDimensions + GetPixel<Color = BinaryColor>,
+            B: OriginDimensions + GetPixel<Color = BinaryColor>,
+        {
+            /// Combine two sources aligned at their top left corners
+            pub const fn new(a: &'a A, b: &'a B) -> Self {
+                Self { a, b }
+            }
+        }
+
+        impl<A, B> OriginDimensions for $name<'_, A, B>
+        where
+            A: OriginDimensions,
+            B: OriginDimensions,
+        {
+            fn size(&self) -> Size {
+                self.a.size().component_max(self.b.size())
+            }
+        }
+
+        impl<A, B> GetPixel for $name<'_, A, B>
+        where
+            A: OriginDimensions + GetPixel<Color = BinaryColor>,
+            B: OriginDimensions + GetPixel<Color = BinaryColor>,
+        {
+            type Color = BinaryColor;
+
+            fn pixel(&self, p: Point) -> Option<Self::Color> {
+                let size = self.size();
+                let inside = p.x >= 0
+                    && p.y >= 0
+                    && (p.x as u32) < size.width
+                    && (p.y as u32) < size.height;
+                inside.then(|| (bit(self.a, p) $op bit(self.b, p)).into())
+            }
+        }
+    };
+}
+
+binary_view!(
+    /// Pixels that are `On` in both sources.
+    And, &
+);
+binary_view!(
+    /// Pixels that are `On` in any of the sources.
+    Or, |
+);
+binary_view!(
+    /// Pixels that are `On` in exactly one of the sources.
+    Xor, ^
+);
+
+/// Inverted binary source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Not<'a, T> {
+    source: &'a T,
+}
+
+impl<'a, T> Not<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Swap the `On` and `Off` pixels of a source
+    pub const fn new(source: &'a T) -> Self {
+        Self { source }
+    }
+}
+
+impl<T> OriginDimensions for Not<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.source.size()
+    }
+}
+
+impl<T> GetPixel for Not<'_, T>
+where
+    T: GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        self.source.pixel(p).map(BinaryColor::invert)
+    }
+}
+
+/// Inverted binary source, drawing the negative of an image.
+///
+/// This is the same view as [`Not`], named for the common use of showing a
+/// highlighted variant of an icon without a second asset.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::Inverted};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let icon = bitmap!["#.", ".#"];
+///
+/// let negative = Inverted::new(&icon);
+/// Image::new(&negative, Point::zero(), BinaryColor::On).draw(&mut display)?;
+/// display.assert_pattern(&[" #", "# "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+pub type Inverted<'a, T> = Not<'a, T>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    fn rows<T: OriginDimensions + GetPixel<Color = BinaryColor>>(view: &T) -> [u8; 2] {
+        core::array::from_fn(|y| {
+            (0..3).fold(0, |acc, x| {
+                (acc << 1) | u8::from(bit(view, Point::new(x, y as i32)))
+            })
+        })
+    }
+
+    #[test]
+    fn test_logic_views() {
+        let a = AsciiImage::from_rows(&["##.", "#.."]).unwrap();
+        let b = AsciiImage::from_rows(&["#.#"]).unwrap();
+
+        assert_eq!(And::new(&a, &b).size(), Size::new(3, 2));
+        assert_eq!(rows(&And::new(&a, &b)), [0b100, 0b000]);
+        assert_eq!(rows(&Or::new(&a, &b)), [0b111, 0b100]);
+        assert_eq!(rows(&Xor::new(&a, &b)), [0b011, 0b100]);
+        assert_eq!(rows(&Not::new(&a)), [0b001, 0b011]);
+        assert_eq!(Not::new(&b).pixel(Point::new(0, 1)), None);
+    }
+}
@@ -0,0 +1,29 @@
+//! Lazy views over binary image sources.
+//!
+//! Views wrap a reference to another source and change how its pixels are
+//! read, without copying any pixel data. Every view is itself a source, so
+//! views can be stacked and passed to [`Image::new`](crate::Image::new).
+
+mod affine;
+mod cropped;
+mod diff;
+mod logic;
+mod padded;
+mod polar;
+mod posterize;
+mod remapped;
+mod shape;
+mod sheared;
+mod threshold;
+
+pub use self::affine::Affine;
+pub use self::cropped::Cropped;
+pub use self::diff::Diff;
+pub use self::logic::{And, Inverted, Not, Or, Xor};
+pub use self::padded::Padded;
+pub use self::polar::Polar;
+pub use self::posterize::Posterized;
+pub use self::remapped::Remapped;
+pub use self::shape::ShapeMask;
+pub use self::sheared::Sheared;
+pub use self::threshold::{LumaWeights, Thresholded, ToLuma};
@@ -0,0 +1,84 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Binary source surrounded by a margin of `Off` pixels.
+///
+/// Layout code that sizes itself from the bounding box of an image gets the
+/// extra spacing without editing the bitmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Padded<'a, T> {
+    source: &'a T,
+    top: u32,
+    right: u32,
+    bottom: u32,
+    left: u32,
+}
+
+impl<'a, T> Padded<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Add the same margin on all sides
+    pub const fn new(source: &'a T, margin: u32) -> Self {
+        Self::with_margins(source, margin, margin, margin, margin)
+    }
+
+    /// Add individual margins, in the same order as CSS
+    pub const fn with_margins(source: &'a T, top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self {
+            source,
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+impl<T> OriginDimensions for Padded<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.source.size() + Size::new(self.left + self.right, self.top + self.bottom)
+    }
+}
+
+impl<T> GetPixel for Padded<'_, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let size = self.size();
+        if p.x < 0 || p.y < 0 || p.x as u32 >= size.width || p.y as u32 >= size.height {
+            return None;
+        }
+
+        let inner = p - Point::new(self.left as i32, self.top as i32);
+        Some(self.source.pixel(inner).unwrap_or(BinaryColor::Off))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_padded_margins() {
+        let source = AsciiImage::from_rows(&["##"]).unwrap();
+        let padded = Padded::with_margins(&source, 1, 0, 2, 3);
+
+        assert_eq!(padded.size(), Size::new(5, 4));
+        assert_eq!(padded.pixel(Point::new(3, 1)), Some(BinaryColor::On));
+        assert_eq!(padded.pixel(Point::new(2, 1)), Some(BinaryColor::Off));
+        assert_eq!(padded.pixel(Point::new(4, 3)), Some(BinaryColor::Off));
+        assert_eq!(padded.pixel(Point::new(5, 0)), None);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::fixed::Fixed;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Binary source sampled in polar coordinates for circular LED layouts.
+///
+/// The view has one column per LED of a ring and one row per ring, starting
+/// with the innermost ring. LED `0` of every ring sits at 12 o'clock and the
+/// LEDs are numbered clockwise. Each LED samples the source at the matching
+/// angle and radius around the center of the source.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::{BinaryColor, Rgb888}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::Polar};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut leds: Display<Rgb888> = Display::default();
+/// let icon = bitmap![".....", "..#..", ".....", ".....", "....."];
+///
+/// // 12 LEDs on one ring, drawn as a 12x1 strip
+/// let ring = Polar::new(&icon, 12, 1);
+/// Image::new(&ring, Point::zero(), Rgb888::RED).draw(&mut leds)?;
+/// assert_eq!(leds.get_pixel(Point::zero()), Some(Rgb888::RED));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Polar<'a, T> {
+    source: &'a T,
+    leds: u32,
+    rings: u32,
+}
+
+impl<'a, T> Polar<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Sample a source for `rings` rings of `leds` LEDs each
+    pub const fn new(source: &'a T, leds: u32, rings: u32) -> Self {
+        Self {
+            source,
+            leds,
+            rings,
+        }
+    }
+
+    /// Returns the source point sampled by an LED
+    pub fn sample_point(&self, led: u32, ring: u32) -> Option<Point> {
+        if led >= self.leds || ring >= self.rings {
+            return None;
+        }
+
+        let size = self.source.size();
+        let degrees = (u64::from(led) * 360 / u64::from(self.leds)) as i32;
+        let (sin, cos) = Fixed::sin_cos(degrees);
+
+        // Rings are spread evenly from the center to the edge of the source,
+        // sampling the middle of their band.
+        let max = size.width.min(size.height) as i32;
+        let radius = Fixed::from_ratio((2 * ring as i32 + 1) * max, 4 * self.rings as i32)?;
+
+        let center_x = Fixed::from_ratio(size.width as i32, 2)?;
+        let center_y = Fixed::from_ratio(size.height as i32, 2)?;
+        let x = center_x + sin * radius;
+        let y = center_y - cos * radius;
+        Some(Point::new(x.floor(), y.floor()))
+    }
+}
+
+impl<T> OriginDimensions for Polar<'_, T> {
+    fn size(&self) -> Size {
+        Size::new(self.leds, self.rings)
+    }
+}
+
+impl<T> GetPixel for Polar<'_, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let (led, ring) = (u32::try_from(p.x).ok()?, u32::try_from(p.y).ok()?);
+        let point = self.sample_point(led, ring)?;
+        Some(self.source.pixel(point).unwrap_or(BinaryColor::Off))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_polar_sample_points() {
+        let source = AsciiImage::from_rows(&["........"; 8]).unwrap();
+        let polar = Polar::new(&source, 4, 2);
+
+        assert_eq!(polar.sample_point(0, 0), Some(Point::new(4, 3)));
+        assert_eq!(polar.sample_point(0, 1), Some(Point::new(4, 1)));
+        assert_eq!(polar.sample_point(1, 1), Some(Point::new(7, 4)));
+        assert_eq!(polar.sample_point(2, 1), Some(Point::new(4, 7)));
+        assert_eq!(polar.sample_point(3, 1), Some(Point::new(1, 4)));
+        assert_eq!(polar.sample_point(4, 0), None);
+    }
+}
@@ -0,0 +1,87 @@
+use super::{LumaWeights, ToLuma};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::Gray8,
+    prelude::{Point, Size},
+};
+
+/// Grayscale source quantized to a small number of levels.
+///
+/// As a source it yields the quantized `Gray8` colors. [`Posterized::level`]
+/// returns the level index instead, which can be mapped to the gray levels of
+/// an e-paper display with a [`Paletted`](crate::Paletted) image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Posterized<'a, T> {
+    source: &'a T,
+    levels: u8,
+}
+
+impl<'a, T, C> Posterized<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = C>,
+    C: ToLuma,
+{
+    /// Quantize a source to `levels` levels
+    ///
+    /// Returns `None` if there are less than two levels.
+    pub const fn new(source: &'a T, levels: u8) -> Option<Self> {
+        if levels < 2 {
+            return None;
+        }
+        Some(Self { source, levels })
+    }
+
+    /// Returns the number of levels
+    pub const fn levels(&self) -> u8 {
+        self.levels
+    }
+
+    /// Returns the level of a pixel, from 0 for black to `levels - 1` for white
+    pub fn level(&self, p: Point) -> Option<u8> {
+        let luma = self.source.pixel(p)?.to_luma(&LumaWeights::BT601);
+        Some((u32::from(luma) * u32::from(self.levels) / 256) as u8)
+    }
+}
+
+impl<T> OriginDimensions for Posterized<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.source.size()
+    }
+}
+
+impl<T, C> GetPixel for Posterized<'_, T>
+where
+    T: OriginDimensions + GetPixel<Color = C>,
+    C: ToLuma,
+{
+    type Color = Gray8;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let level = u32::from(self.level(p)?);
+        Some(Gray8::new((level * 255 / u32::from(self.levels - 1)) as u8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, pixelcolor::GrayColor};
+
+    #[test]
+    fn test_posterize_four_levels() {
+        let image: ImageRaw<Gray8> = ImageRaw::new(&[0, 63, 64, 191, 192, 255], 6);
+        let posterized = Posterized::new(&image, 4).unwrap();
+
+        let levels: [_; 6] = core::array::from_fn(|x| posterized.level(Point::new(x as i32, 0)));
+        assert_eq!(levels, [0, 0, 1, 2, 3, 3].map(Some));
+        assert_eq!(
+            posterized.pixel(Point::new(2, 0)).map(|c| c.luma()),
+            Some(85)
+        );
+        assert!(Posterized::new(&image, 1).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use core::fmt;
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+
+/// Binary source with custom coordinate mapping.
+///
+/// The closure maps every point of the view to the point of the source that
+/// is shown there, or `None` to leave it `Off`. This covers panel geometries
+/// that don't fit any of the other views, like split or mirrored segments.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::Remapped};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let source = bitmap!["#...", "..#."];
+///
+/// // A panel where the second row is wired right to left
+/// let panel = Remapped::new(&source, Size::new(4, 2), |p: Point| {
+///     Some(if p.y == 1 { Point::new(3 - p.x, 1) } else { p })
+/// });
+/// Image::new(&panel, Point::zero(), BinaryColor::On).draw(&mut display)?;
+/// display.assert_pattern(&["#   ", " #  "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct Remapped<'a, T, F> {
+    source: &'a T,
+    size: Size,
+    map: F,
+}
+
+impl<'a, T, F> Remapped<'a, T, F>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    F: Fn(Point) -> Option<Point>,
+{
+    /// Create a view of the given size that reads the source through `map`
+    pub const fn new(source: &'a T, size: Size, map: F) -> Self {
+        Self { source, size, map }
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for Remapped<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Remapped")
+            .field("source", &self.source)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> OriginDimensions for Remapped<'_, T, F> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<T, F> GetPixel for Remapped<'_, T, F>
+where
+    T: GetPixel<Color = BinaryColor>,
+    F: Fn(Point) -> Option<Point>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        if !Rectangle::new(Point::zero(), self.size).contains(p) {
+            return None;
+        }
+
+        let color = (self.map)(p).and_then(|p| self.source.pixel(p));
+        Some(color.unwrap_or(BinaryColor::Off))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_remapped_outside() {
+        let source = AsciiImage::from_rows(&["#"]).unwrap();
+        let remapped = Remapped::new(&source, Size::new(2, 1), |p: Point| {
+            (p.x == 0).then_some(Point::new(p.x + 5, 0))
+        });
+
+        assert_eq!(remapped.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(remapped.pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(remapped.pixel(Point::new(2, 0)), None);
+
+        let identity = Remapped::new(&source, Size::new(1, 1), Some);
+        assert_eq!(identity.pixel(Point::zero()), Some(BinaryColor::On));
+    }
+}
@@ -0,0 +1,107 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, Point, Size},
+    primitives::{Circle, ContainsPoint, Ellipse, Rectangle, RoundedRectangle},
+};
+
+/// Binary source that is `On` inside a primitive shape.
+///
+/// Mostly useful as a mask for [`Image::with_mask`](crate::Image::with_mask),
+/// to give thumbnails rounded corners or crop avatars to a circle without a
+/// separate mask bitmap.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::ShapeMask};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let avatar = bitmap!["###", "###", "###"];
+///
+/// let mask = ShapeMask::circle(3);
+/// Image::new(&avatar, Point::zero(), Rgb565::RED)
+///     .with_mask(&mask)
+///     .draw(&mut display)?;
+/// display.assert_pattern(&[" R ", "RRR", " R "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShapeMask<S> {
+    shape: S,
+}
+
+impl<S> ShapeMask<S>
+where
+    S: ContainsPoint + Dimensions,
+{
+    /// Use any primitive as mask
+    pub const fn new(shape: S) -> Self {
+        Self { shape }
+    }
+}
+
+impl ShapeMask<RoundedRectangle> {
+    /// Rectangle of the given size with rounded corners
+    pub fn rounded(size: Size, radius: u32) -> Self {
+        Self::new(RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::zero(), size),
+            Size::new(radius, radius),
+        ))
+    }
+}
+
+impl ShapeMask<Circle> {
+    /// Circle with the given diameter
+    pub const fn circle(diameter: u32) -> Self {
+        Self::new(Circle::new(Point::zero(), diameter))
+    }
+}
+
+impl ShapeMask<Ellipse> {
+    /// Ellipse filling a rectangle of the given size
+    pub const fn ellipse(size: Size) -> Self {
+        Self::new(Ellipse::new(Point::zero(), size))
+    }
+}
+
+impl<S> OriginDimensions for ShapeMask<S>
+where
+    S: Dimensions,
+{
+    fn size(&self) -> Size {
+        let bounding_box = self.shape.bounding_box();
+        bounding_box.bottom_right().map_or(Size::zero(), |p| {
+            Size::new(p.x.max(0) as u32 + 1, p.y.max(0) as u32 + 1)
+        })
+    }
+}
+
+impl<S> GetPixel for ShapeMask<S>
+where
+    S: ContainsPoint + Dimensions,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        Rectangle::new(Point::zero(), self.size())
+            .contains(p)
+            .then(|| self.shape.contains(p).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounded_mask() {
+        let mask = ShapeMask::rounded(Size::new(6, 4), 2);
+        assert_eq!(mask.size(), Size::new(6, 4));
+        assert_eq!(mask.pixel(Point::new(0, 0)), Some(BinaryColor::Off));
+        assert_eq!(mask.pixel(Point::new(0, 2)), Some(BinaryColor::On));
+        assert_eq!(mask.pixel(Point::new(3, 0)), Some(BinaryColor::On));
+        assert_eq!(mask.pixel(Point::new(6, 0)), None);
+    }
+}
@@ -0,0 +1,119 @@
+use super::Affine;
+use crate::fixed::{Fixed, Matrix};
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Point, Size},
+};
+
+/// Binary source with a shear applied.
+///
+/// A horizontal shear moves every row by `factor * y` pixels, which turns an
+/// upright logo into an italic one. A vertical shear moves every column by
+/// `factor * x` pixels instead. The view grows to fit the sheared image, the
+/// uncovered corners are `Off`.
+///
+/// This is a shorthand for an [`Affine`] view with a single shear, the
+/// pixels are sampled the same way.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+/// use embedded_graphics_colorcast::{Image, bitmap, view::Sheared};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+/// let bar = bitmap!["#", "#", "#"];
+///
+/// let italic = Sheared::horizontal(&bar, -1, 2).unwrap();
+/// Image::new(&italic, Point::zero(), BinaryColor::On).draw(&mut display)?;
+/// display.assert_pattern(&["  #", " # ", " # "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sheared<'a, T> {
+    affine: Affine<'a, T>,
+}
+
+impl<'a, T> Sheared<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    /// Shift rows by `numerator / denominator` pixels per row
+    ///
+    /// Positive factors move lower rows to the right. Returns `None` if the
+    /// denominator is zero.
+    pub fn horizontal(source: &'a T, numerator: i32, denominator: i32) -> Option<Self> {
+        let factor = Fixed::from_ratio(numerator, denominator)?;
+        Some(Self::new(source, Matrix::shear(factor, Fixed::from_int(0))))
+    }
+
+    /// Shift columns by `numerator / denominator` pixels per column
+    ///
+    /// Positive factors move columns on the right down. Returns `None` if the
+    /// denominator is zero.
+    pub fn vertical(source: &'a T, numerator: i32, denominator: i32) -> Option<Self> {
+        let factor = Fixed::from_ratio(numerator, denominator)?;
+        Some(Self::new(source, Matrix::shear(Fixed::from_int(0), factor)))
+    }
+
+    fn new(source: &'a T, matrix: Matrix) -> Self {
+        Self {
+            affine: Affine::with_matrix(source, matrix),
+        }
+    }
+}
+
+impl<T> OriginDimensions for Sheared<'_, T> {
+    fn size(&self) -> Size {
+        self.affine.size()
+    }
+}
+
+impl<T> GetPixel for Sheared<'_, T>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        self.affine.pixel(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+
+    #[test]
+    fn test_sheared_vertical() {
+        let source = AsciiImage::from_rows(&["###"]).unwrap();
+        let sheared = Sheared::vertical(&source, 1, 1).unwrap();
+
+        // The view covers the sheared outline, which reaches one row further.
+        assert_eq!(sheared.size(), Size::new(3, 4));
+        assert_eq!(sheared.pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(sheared.pixel(Point::new(1, 1)), Some(BinaryColor::On));
+        assert_eq!(sheared.pixel(Point::new(2, 2)), Some(BinaryColor::On));
+        assert_eq!(sheared.pixel(Point::new(2, 0)), Some(BinaryColor::Off));
+        assert_eq!(sheared.pixel(Point::new(2, 3)), Some(BinaryColor::Off));
+        assert!(Sheared::vertical(&source, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_sheared_matches_affine() {
+        let source = AsciiImage::from_rows(&["##.", "#.#", ".##", "#.."]).unwrap();
+        let sheared = Sheared::horizontal(&source, 2, 3).unwrap();
+        let affine = Affine::new(&source).shear(2, 3).unwrap();
+
+        assert_eq!(sheared.size(), affine.size());
+        let size = affine.size();
+        for y in -1..=size.height as i32 {
+            for x in -1..=size.width as i32 {
+                let p = Point::new(x, y);
+                assert_eq!(sheared.pixel(p), affine.pixel(p));
+            }
+        }
+    }
+}
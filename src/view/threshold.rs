@@ -0,0 +1,191 @@
+use embedded_graphics::{
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::{
+        Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555,
+        Rgb565, Rgb666, Rgb888, RgbColor,
+    },
+    prelude::{PixelColor, Point, Size},
+};
+
+/// Weights of the color channels for computing the luma of a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LumaWeights {
+    /// Weight of the red channel
+    pub red: u32,
+    /// Weight of the green channel
+    pub green: u32,
+    /// Weight of the blue channel
+    pub blue: u32,
+}
+
+impl LumaWeights {
+    /// ITU-R BT.601 weights, as used by most image libraries
+    pub const BT601: Self = Self::new(299, 587, 114);
+
+    /// ITU-R BT.709 weights, for sRGB content
+    pub const BT709: Self = Self::new(2126, 7152, 722);
+
+    /// Custom channel weights
+    ///
+    /// The weights are relative to each other and don't need to add up to any
+    /// particular value.
+    pub const fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Returns the luma of 8 bit channel values
+    pub const fn luma(&self, r: u8, g: u8, b: u8) -> u8 {
+        // Computed in `u64`, which fits any weights without overflowing.
+        let (red, green, blue) = (self.red as u64, self.green as u64, self.blue as u64);
+        let total = red + green + blue;
+        if total == 0 {
+            return 0;
+        }
+
+        let sum = r as u64 * red + g as u64 * green + b as u64 * blue;
+        (sum / total) as u8
+    }
+
+    /// Returns the luma of any RGB color, scaled to 8 bits
+    pub fn luma_of<C: RgbColor>(&self, color: C) -> u8 {
+        let scale = |value: u8, max: u8| (u32::from(value) * 255 / u32::from(max)) as u8;
+        self.luma(
+            scale(color.r(), C::MAX_R),
+            scale(color.g(), C::MAX_G),
+            scale(color.b(), C::MAX_B),
+        )
+    }
+}
+
+impl Default for LumaWeights {
+    fn default() -> Self {
+        Self::BT601
+    }
+}
+
+/// Colors that can be converted to an 8 bit luma.
+pub trait ToLuma: PixelColor {
+    /// Returns the luma of the color, using `weights` for RGB colors
+    fn to_luma(self, weights: &LumaWeights) -> u8;
+}
+
+macro_rules! impl_to_luma_rgb {
+    ($($color:ty),*) => {
+        $(
+            impl ToLuma for $color {
+                fn to_luma(self, weights: &LumaWeights) -> u8 {
+                    weights.luma_of(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_luma_rgb!(
+    Rgb555, Rgb565, Rgb666, Rgb888, Bgr555, Bgr565, Bgr666, Bgr888
+);
+
+macro_rules! impl_to_luma_gray {
+    ($($color:ty),*) => {
+        $(
+            impl ToLuma for $color {
+                fn to_luma(self, _weights: &LumaWeights) -> u8 {
+                    (u32::from(self.luma()) * 255 / u32::from(Self::WHITE.luma())) as u8
+                }
+            }
+        )*
+    };
+}
+
+impl_to_luma_gray!(Gray2, Gray4, Gray8);
+
+/// RGB or grayscale source converted to a binary source.
+///
+/// Pixels with a luma of at least the threshold are `On`, darker pixels are
+/// `Off`. This allows full color assets, like an `ImageRaw<Rgb565>`, to be
+/// used on monochrome hardware.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRawBE, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::{Image, view::{LumaWeights, Thresholded}};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<BinaryColor> = Display::default();
+///
+/// let data = [0xff, 0xff, 0x00, 0x1f];
+/// let raw: ImageRawBE<Rgb565> = ImageRawBE::new(&data, 2);
+/// let binary = Thresholded::new(&raw, 128).with_weights(LumaWeights::BT709);
+///
+/// Image::new(&binary, Point::zero(), BinaryColor::On).draw(&mut display)?;
+/// display.assert_pattern(&["# "]);
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Thresholded<'a, T> {
+    source: &'a T,
+    weights: LumaWeights,
+    threshold: u8,
+}
+
+impl<'a, T, C> Thresholded<'a, T>
+where
+    T: OriginDimensions + GetPixel<Color = C>,
+    C: ToLuma,
+{
+    /// Threshold a source using BT.601 luma
+    pub const fn new(source: &'a T, threshold: u8) -> Self {
+        Self {
+            source,
+            weights: LumaWeights::BT601,
+            threshold,
+        }
+    }
+
+    /// Use different channel weights for computing the luma
+    pub const fn with_weights(mut self, weights: LumaWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+}
+
+impl<T> OriginDimensions for Thresholded<'_, T>
+where
+    T: OriginDimensions,
+{
+    fn size(&self) -> Size {
+        self.source.size()
+    }
+}
+
+impl<T, C> GetPixel for Thresholded<'_, T>
+where
+    T: GetPixel<Color = C>,
+    C: ToLuma,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<Self::Color> {
+        let color = self.source.pixel(p)?;
+        Some((color.to_luma(&self.weights) >= self.threshold).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luma_weights() {
+        assert_eq!(LumaWeights::BT601.luma(255, 255, 255), 255);
+        assert_eq!(LumaWeights::BT601.luma_of(Rgb888::GREEN), 149);
+        assert_eq!(LumaWeights::BT709.luma_of(Rgb565::GREEN), 182);
+        assert_eq!(LumaWeights::new(0, 0, 1).luma_of(Rgb565::BLUE), 255);
+        assert_eq!(LumaWeights::new(0, 0, 0).luma(1, 2, 3), 0);
+        assert_eq!(
+            LumaWeights::new(u32::MAX, u32::MAX, 0).luma(255, 255, 9),
+            255
+        );
+        assert_eq!(Gray4::new(5).to_luma(&LumaWeights::BT601), 85);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{Image, geometry};
+use embedded_graphics::{
+    Drawable,
+    geometry::{AnchorY, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{Dimensions, DrawTarget, PixelColor, Point, Transform},
+    primitives::Rectangle,
+    text::{Baseline, Text, renderer::TextRenderer},
+};
+
+/// Tinted icon followed by a text label.
+///
+/// The label is placed to the right of the icon, separated by a gap, and
+/// aligned to the top, center or bottom of the icon.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     mono_font::{MonoTextStyle, ascii::FONT_6X10},
+///     pixelcolor::{BinaryColor, Rgb565},
+///     prelude::*,
+/// };
+/// use embedded_graphics_colorcast::{Image, widget::IconLabel};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+///
+/// let raw: ImageRaw<BinaryColor> = ImageRaw::new(&[0xff, 0xff, 0xff, 0xff], 8);
+/// let icon = Image::new(&raw, Point::new(0, 4), Rgb565::GREEN);
+/// let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+///
+/// IconLabel::new(icon, "Wi-Fi", style).with_gap(4).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IconLabel<'a, T, C, S>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    icon: Image<'a, T, C>,
+    text: &'a str,
+    style: S,
+    gap: u32,
+    alignment: AnchorY,
+}
+
+impl<'a, T, C, S> IconLabel<'a, T, C, S>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    S: TextRenderer<Color = C> + Clone,
+{
+    /// Create a new icon label, with the label vertically centered on the icon
+    pub const fn new(icon: Image<'a, T, C>, text: &'a str, style: S) -> Self {
+        Self {
+            icon,
+            text,
+            style,
+            gap: 2,
+            alignment: AnchorY::Center,
+        }
+    }
+
+    /// Put `gap` pixels of space between the icon and the label
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Align the label to the top, center or bottom of the icon
+    pub fn with_alignment(mut self, alignment: AnchorY) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the icon
+    pub const fn icon(&self) -> &Image<'a, T, C> {
+        &self.icon
+    }
+
+    /// Returns the label as a `Text` drawable
+    pub fn label(&self) -> Text<'a, S> {
+        let icon = self.icon.bounding_box();
+        let x = icon.top_left.x + (icon.size.width + self.gap) as i32;
+        let (y, baseline) = match self.alignment {
+            AnchorY::Top => (icon.top_left.y, Baseline::Top),
+            AnchorY::Center => (icon.center().y, Baseline::Middle),
+            AnchorY::Bottom => (
+                icon.top_left.y + icon.size.height.saturating_sub(1) as i32,
+                Baseline::Bottom,
+            ),
+        };
+
+        Text::with_baseline(self.text, Point::new(x, y), self.style.clone(), baseline)
+    }
+}
+
+impl<T, C, S> Drawable for IconLabel<'_, T, C, S>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    S: TextRenderer<Color = C> + Clone,
+{
+    type Color = C;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.icon.draw(target)?;
+        self.label().draw(target)?;
+        Ok(())
+    }
+}
+
+impl<T, C, S> Dimensions for IconLabel<'_, T, C, S>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    S: TextRenderer<Color = C> + Clone,
+{
+    fn bounding_box(&self) -> Rectangle {
+        geometry::union(&self.icon.bounding_box(), &self.label().bounding_box())
+    }
+}
+
+impl<T, C, S> Transform for IconLabel<'_, T, C, S>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+    S: Clone,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            icon: self.icon.translate(by),
+            style: self.style.clone(),
+            ..*self
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.icon.translate_mut(by);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        image::ImageRaw,
+        mono_font::{MonoTextStyle, ascii::FONT_6X10},
+        pixelcolor::Rgb565,
+        prelude::{RgbColor, Size},
+    };
+
+    #[test]
+    fn test_icon_label_layout() {
+        let raw = ImageRaw::<BinaryColor>::new(&[0xff; 12], 8);
+        let icon = Image::new(&raw, Point::new(1, 1), Rgb565::GREEN);
+        let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+
+        let widget = IconLabel::new(icon, "ab", style)
+            .with_gap(3)
+            .with_alignment(AnchorY::Top);
+        assert_eq!(widget.label().position, Point::new(12, 1));
+        assert_eq!(
+            widget.bounding_box(),
+            Rectangle::new(Point::new(1, 1), Size::new(23, 12))
+        );
+
+        let moved = widget.translate(Point::new(2, 0));
+        assert_eq!(moved.label().position, Point::new(14, 1));
+    }
+}
@@ -0,0 +1,207 @@
+use super::Widget;
+use crate::{Image, source::IconStrip, view::Cropped};
+use embedded_graphics::{
+    Drawable,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// How the indicator turns a value into pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Levels<'a, T> {
+    /// One glyph per level, from empty to full
+    Frames(IconStrip<'a, T>),
+    /// Image of all bars next to each other, revealed from the left
+    Bars(&'a T, u32),
+}
+
+/// Battery or signal level indicator.
+///
+/// The indicator shows a value between 0 and 100, either by picking one
+/// frame of an [`IconStrip`], or by revealing a number of bars from an image
+/// that shows all bars. The tint color can change with the value, for
+/// example to show a low battery in red.
+///
+/// ```rust
+/// use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+/// use embedded_graphics_colorcast::{source::{AsciiImage, IconStrip}, widget::{Indicator, Widget}};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let frames = AsciiImage::from_rows(&["###.###.###.", "#.#.##..###."]).unwrap();
+/// const LOW: [(u8, Rgb565); 1] = [(20, Rgb565::RED)];
+///
+/// let mut battery = Indicator::new(IconStrip::new(&frames, 4), Point::zero(), Rgb565::WHITE)
+///     .with_thresholds(&LOW);
+/// battery.set_value(10);
+/// assert_eq!(battery.color(), Rgb565::RED);
+/// battery.render(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Indicator<'a, T, C> {
+    levels: Levels<'a, T>,
+    position: Point,
+    color: C,
+    thresholds: &'a [(u8, C)],
+    value: u8,
+    dirty: bool,
+}
+
+impl<'a, T, C> Indicator<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create an indicator that shows one frame of `strip` per level
+    ///
+    /// The first frame is shown for 0, the last one for 100.
+    pub const fn new(strip: IconStrip<'a, T>, position: Point, color: C) -> Self {
+        Self::with_levels(Levels::Frames(strip), position, color)
+    }
+
+    /// Create an indicator that reveals `bars` equally wide bars of `image`
+    pub const fn bars(image: &'a T, bars: u32, position: Point, color: C) -> Self {
+        Self::with_levels(Levels::Bars(image, bars), position, color)
+    }
+
+    const fn with_levels(levels: Levels<'a, T>, position: Point, color: C) -> Self {
+        Self {
+            levels,
+            position,
+            color,
+            thresholds: &[],
+            value: 0,
+            dirty: true,
+        }
+    }
+
+    /// Change the color for low values
+    ///
+    /// Every `(limit, color)` entry applies to values below `limit`, the first
+    /// matching entry is used. Values that don't match any entry use the
+    /// default color.
+    pub const fn with_thresholds(mut self, thresholds: &'a [(u8, C)]) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Returns the current value
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Change the value and return the area that needs to be redrawn
+    ///
+    /// Values above 100 are clamped. The returned rectangle is zero sized if
+    /// neither the level nor the color changed.
+    pub fn set_value(&mut self, value: u8) -> Rectangle {
+        let (level, color) = (self.level(), self.color());
+        self.value = value.min(100);
+        if level == self.level() && color == self.color() {
+            return Rectangle::zero();
+        }
+        self.dirty = true;
+        self.area()
+    }
+
+    /// Returns the tint color for the current value
+    pub fn color(&self) -> C {
+        self.thresholds
+            .iter()
+            .find(|(limit, _)| self.value < *limit)
+            .map_or(self.color, |(_, color)| *color)
+    }
+
+    /// Returns the number of the frame or bars shown for the current value
+    pub fn level(&self) -> u32 {
+        let steps = match &self.levels {
+            Levels::Frames(strip) => strip.len().saturating_sub(1) as u32,
+            Levels::Bars(_, bars) => *bars,
+        };
+        (steps * u32::from(self.value) + 50) / 100
+    }
+}
+
+impl<T, C> Widget<C> for Indicator<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let image = match &self.levels {
+            Levels::Frames(strip) => match strip.glyph(self.level() as usize) {
+                Some(glyph) => glyph,
+                None => return Ok(()),
+            },
+            Levels::Bars(image, bars) => {
+                let bar_width = image.size().width.checked_div(*bars).unwrap_or(0);
+                let size = Size::new(bar_width * self.level(), image.size().height);
+                Cropped::new(*image, &Rectangle::new(Point::zero(), size))
+            }
+        };
+        Image::new(&image, self.position, self.color()).draw(target)
+    }
+
+    fn area(&self) -> Rectangle {
+        let size = match &self.levels {
+            Levels::Frames(strip) => strip.glyph_size(),
+            Levels::Bars(image, _) => image.size(),
+        };
+        Rectangle::new(self.position, size)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+    use embedded_graphics::mock_display::MockDisplay;
+
+    #[test]
+    fn test_indicator_levels() {
+        let frames = AsciiImage::from_rows(&["#..##.###"]).unwrap();
+        let mut indicator =
+            Indicator::new(IconStrip::new(&frames, 3), Point::zero(), BinaryColor::On);
+        assert_eq!(indicator.level(), 0);
+        assert_eq!(indicator.set_value(20), Rectangle::zero());
+        assert_eq!(
+            indicator.set_value(60),
+            Rectangle::new(Point::zero(), Size::new(3, 1))
+        );
+        assert_eq!(indicator.level(), 1);
+
+        let mut display = MockDisplay::new();
+        indicator.render(&mut display).unwrap();
+        display.assert_pattern(&["##"]);
+    }
+
+    #[test]
+    fn test_indicator_bars() {
+        let bars = AsciiImage::from_rows(&["#.#.#.#."]).unwrap();
+        const LOW: [(u8, BinaryColor); 1] = [(30, BinaryColor::Off)];
+        let mut indicator =
+            Indicator::bars(&bars, 4, Point::zero(), BinaryColor::On).with_thresholds(&LOW);
+        indicator.set_value(25);
+        assert_eq!(indicator.level(), 1);
+        assert_eq!(indicator.color(), BinaryColor::Off);
+
+        let mut display = MockDisplay::new();
+        indicator.render(&mut display).unwrap();
+        display.assert_pattern(&["."]);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::geometry;
+use embedded_graphics::{
+    prelude::{DrawTarget, PixelColor},
+    primitives::Rectangle,
+};
+
+/// Retained-mode UI element.
+///
+/// A widget knows the area it covers and whether it changed since it was
+/// last drawn, so a [`WidgetList`] only needs to redraw the widgets that
+/// actually changed.
+///
+/// Tuples of up to eight widgets, arrays and slices of widgets are widgets
+/// too, which is how screens with different widget types are put together.
+pub trait Widget<C: PixelColor> {
+    /// Draw the widget
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>;
+
+    /// Returns the area covered by the widget
+    fn area(&self) -> Rectangle;
+
+    /// Returns `true` if the widget changed since it was last drawn
+    fn is_dirty(&self) -> bool;
+
+    /// Mark the widget as changed or unchanged
+    fn set_dirty(&mut self, dirty: bool);
+
+    /// Draw the widget if it's dirty and return the affected area
+    ///
+    /// If `background` is set, the area is cleared before drawing. The
+    /// returned rectangle is zero sized if nothing was drawn.
+    fn render_dirty<D>(
+        &mut self,
+        target: &mut D,
+        background: Option<C>,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if !self.is_dirty() {
+            return Ok(Rectangle::zero());
+        }
+        if let Some(color) = background {
+            target.fill_solid(&self.area(), color)?;
+        }
+        self.render(target)?;
+        self.set_dirty(false);
+        Ok(self.area())
+    }
+}
+
+impl<C, W> Widget<C> for [W]
+where
+    C: PixelColor,
+    W: Widget<C>,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.iter().try_for_each(|widget| widget.render(target))
+    }
+
+    fn area(&self) -> Rectangle {
+        self.iter().fold(Rectangle::zero(), |area, widget| {
+            geometry::union(&area, &widget.area())
+        })
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.iter().any(Widget::is_dirty)
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.iter_mut().for_each(|widget| widget.set_dirty(dirty));
+    }
+
+    fn render_dirty<D>(
+        &mut self,
+        target: &mut D,
+        background: Option<C>,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut area = Rectangle::zero();
+        for widget in self {
+            area = geometry::union(&area, &widget.render_dirty(target, background)?);
+        }
+        Ok(area)
+    }
+}
+
+impl<C, W, const N: usize> Widget<C> for [W; N]
+where
+    C: PixelColor,
+    W: Widget<C>,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.as_slice().render(target)
+    }
+
+    fn area(&self) -> Rectangle {
+        self.as_slice().area()
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.as_slice().is_dirty()
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.as_mut_slice().set_dirty(dirty);
+    }
+
+    fn render_dirty<D>(
+        &mut self,
+        target: &mut D,
+        background: Option<C>,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.as_mut_slice().render_dirty(target, background)
+    }
+}
+
+impl<C, W> Widget<C> for &mut W
+where
+    C: PixelColor,
+    W: Widget<C> + ?Sized,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        (**self).render(target)
+    }
+
+    fn area(&self) -> Rectangle {
+        (**self).area()
+    }
+
+    fn is_dirty(&self) -> bool {
+        (**self).is_dirty()
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        (**self).set_dirty(dirty);
+    }
+
+    fn render_dirty<D>(
+        &mut self,
+        target: &mut D,
+        background: Option<C>,
+    ) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        (**self).render_dirty(target, background)
+    }
+}
+
+macro_rules! tuple_widget {
+    ($($name:ident: $index:tt),+) => {
+        impl<C, $($name),+> Widget<C> for ($($name,)+)
+        where
+            C: PixelColor,
+            $($name: Widget<C>,)+
+        {
+            fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+            where
+                D: DrawTarget<Color = C>,
+            {
+                $(self.$index.render(target)?;)+
+                Ok(())
+            }
+
+            fn area(&self) -> Rectangle {
+                let area = Rectangle::zero();
+                $(let area = geometry::union(&area, &self.$index.area());)+
+                area
+            }
+
+            fn is_dirty(&self) -> bool {
+                $(self.$index.is_dirty())||+
+            }
+
+            fn set_dirty(&mut self, dirty: bool) {
+                $(self.$index.set_dirty(dirty);)+
+            }
+
+            fn render_dirty<D>(&mut self, target: &mut D, background: Option<C>) -> Result<Rectangle, D::Error>
+            where
+                D: DrawTarget<Color = C>,
+            {
+                let area = Rectangle::zero();
+                $(let area = geometry::union(&area, &self.$index.render_dirty(target, background)?);)+
+                Ok(area)
+            }
+        }
+    };
+}
+
+tuple_widget!(A: 0);
+tuple_widget!(A: 0, B: 1);
+tuple_widget!(A: 0, B: 1, E: 2);
+tuple_widget!(A: 0, B: 1, E: 2, F: 3);
+tuple_widget!(A: 0, B: 1, E: 2, F: 3, G: 4);
+tuple_widget!(A: 0, B: 1, E: 2, F: 3, G: 4, H: 5);
+tuple_widget!(A: 0, B: 1, E: 2, F: 3, G: 4, H: 5, I: 6);
+tuple_widget!(A: 0, B: 1, E: 2, F: 3, G: 4, H: 5, I: 6, J: 7);
+
+/// Renderer for a screen of widgets.
+///
+/// Every call to [`WidgetList::draw`] redraws only the widgets that changed.
+/// If a background color is set, the area of a changed widget is cleared
+/// first, so pixels the widget no longer covers are erased. The old area of
+/// a widget that moved isn't cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WidgetList<W, C> {
+    widgets: W,
+    background: Option<C>,
+}
+
+impl<W, C> WidgetList<W, C>
+where
+    W: Widget<C>,
+    C: PixelColor,
+{
+    /// Create a new list, with all widgets marked dirty
+    pub fn new(mut widgets: W) -> Self {
+        widgets.set_dirty(true);
+        Self {
+            widgets,
+            background: None,
+        }
+    }
+
+    /// Clear the area of changed widgets with the given color before drawing
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Returns the widgets
+    pub const fn widgets(&self) -> &W {
+        &self.widgets
+    }
+
+    /// Returns the widgets for changing them
+    pub fn widgets_mut(&mut self) -> &mut W {
+        &mut self.widgets
+    }
+
+    /// Redraw all widgets on the next call to [`WidgetList::draw`]
+    pub fn invalidate(&mut self) {
+        self.widgets.set_dirty(true);
+    }
+
+    /// Draw the changed widgets and return the affected area
+    ///
+    /// The returned rectangle is zero sized if nothing changed.
+    pub fn draw<D>(&mut self, target: &mut D) -> Result<Rectangle, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.widgets.render_dirty(target, self.background)
+    }
+
+    /// Returns the widgets
+    pub fn into_inner(self) -> W {
+        self.widgets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        prelude::{Point, Size},
+    };
+
+    struct Dot {
+        position: Point,
+        dirty: bool,
+    }
+
+    impl Widget<BinaryColor> for Dot {
+        fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = BinaryColor>,
+        {
+            target.fill_solid(&self.area(), BinaryColor::On)
+        }
+
+        fn area(&self) -> Rectangle {
+            Rectangle::new(self.position, Size::new(1, 1))
+        }
+
+        fn is_dirty(&self) -> bool {
+            self.dirty
+        }
+
+        fn set_dirty(&mut self, dirty: bool) {
+            self.dirty = dirty;
+        }
+    }
+
+    #[test]
+    fn test_widget_list_redraws_dirty() {
+        let dot = |x| Dot {
+            position: Point::new(x, 0),
+            dirty: false,
+        };
+        let mut list = WidgetList::new((dot(0), [dot(2), dot(3)]));
+
+        let mut display = MockDisplay::new();
+        let area = list.draw(&mut display).unwrap();
+        assert_eq!(area, Rectangle::new(Point::zero(), Size::new(4, 1)));
+        display.assert_pattern(&["# ##"]);
+
+        assert_eq!(list.draw(&mut display).unwrap(), Rectangle::zero());
+
+        list.widgets_mut().1[1].set_dirty(true);
+        let mut display = MockDisplay::new();
+        list.draw(&mut display).unwrap();
+        display.assert_pattern(&["   #"]);
+
+        let mut list = list.with_background(BinaryColor::Off);
+        list.widgets_mut().0.position = Point::new(1, 0);
+        list.widgets_mut().0.set_dirty(true);
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        list.draw(&mut display).unwrap();
+        display.assert_pattern(&[" #"]);
+    }
+}
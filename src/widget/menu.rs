@@ -0,0 +1,326 @@
+use super::{IconLabel, Widget};
+use crate::Image;
+use embedded_graphics::{
+    Drawable,
+    geometry::OriginDimensions,
+    image::GetPixel,
+    mono_font::MonoTextStyle,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Single row of a [`Menu`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MenuEntry<'a, T> {
+    /// Icon shown at the start of the row
+    pub icon: &'a T,
+    /// Label shown next to the icon
+    pub label: &'a str,
+}
+
+impl<'a, T> MenuEntry<'a, T> {
+    /// Create a new entry
+    pub const fn new(icon: &'a T, label: &'a str) -> Self {
+        Self { icon, label }
+    }
+}
+
+/// How the selected row of a [`Menu`] stands out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Highlight<C> {
+    /// Draw the icon and label of the selected row in another color
+    Tint(C),
+    /// Fill the selected row with `background` and draw its icon and label
+    /// in `foreground`
+    Invert {
+        /// Color of the row
+        background: C,
+        /// Color of the icon and label
+        foreground: C,
+    },
+}
+
+/// Vertical list of icon and label rows with a selection.
+///
+/// Changing the selection reports the rows that need to be redrawn, which
+/// can then be drawn with [`Menu::render_row`].
+///
+/// ```rust
+/// use embedded_graphics::{
+///     image::ImageRaw,
+///     mono_font::{MonoTextStyle, ascii::FONT_6X10},
+///     pixelcolor::{BinaryColor, Rgb565},
+///     prelude::*,
+/// };
+/// use embedded_graphics_colorcast::widget::{Highlight, Menu, MenuEntry};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// # display.set_allow_overdraw(true);
+/// let icon = ImageRaw::<BinaryColor>::new(&[0xff; 8], 8);
+/// let entries = [MenuEntry::new(&icon, "Wi-Fi"), MenuEntry::new(&icon, "Sound")];
+/// let style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+///
+/// let highlight = Highlight::Tint(Rgb565::YELLOW);
+/// let mut menu = Menu::new(&entries, Point::zero(), 64, style, Rgb565::WHITE, highlight)
+///     .with_background(Rgb565::BLACK);
+/// menu.render_row(&mut display, 0)?;
+///
+/// let changed = menu.select(1);
+/// assert_eq!(changed, Some([0, 1]));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Menu<'a, T, C> {
+    entries: &'a [MenuEntry<'a, T>],
+    position: Point,
+    width: u32,
+    row_height: u32,
+    style: MonoTextStyle<'a, C>,
+    color: C,
+    background: Option<C>,
+    highlight: Highlight<C>,
+    selected: usize,
+    dirty: bool,
+}
+
+impl<'a, T, C> Menu<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create a new menu with the first entry selected
+    ///
+    /// Icons are tinted with `color`, labels use the text color of `style`.
+    /// The rows are as high as the tallest icon or the font, whichever is
+    /// larger.
+    pub fn new(
+        entries: &'a [MenuEntry<'a, T>],
+        position: Point,
+        width: u32,
+        style: MonoTextStyle<'a, C>,
+        color: C,
+        highlight: Highlight<C>,
+    ) -> Self {
+        let row_height = entries
+            .iter()
+            .map(|entry| entry.icon.size().height)
+            .fold(style.font.character_size.height, u32::max);
+
+        Self {
+            entries,
+            position,
+            width,
+            row_height,
+            style,
+            color,
+            background: None,
+            highlight,
+            selected: 0,
+            dirty: true,
+        }
+    }
+
+    /// Clear rows with the given color before drawing them
+    ///
+    /// This is needed to remove the highlight of [`Highlight::Invert`] when
+    /// the selection moves.
+    pub fn with_background(mut self, color: C) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Use rows of the given height
+    pub fn with_row_height(mut self, height: u32) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Returns the index of the selected row
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Select a row and return the previously and newly selected rows
+    ///
+    /// Returns `None` if the row is out of range or already selected.
+    pub fn select(&mut self, row: usize) -> Option<[usize; 2]> {
+        if row >= self.entries.len() || row == self.selected {
+            return None;
+        }
+        let previous = core::mem::replace(&mut self.selected, row);
+        self.dirty = true;
+        Some([previous, row])
+    }
+
+    /// Select the next row, wrapping around at the end
+    pub fn select_next(&mut self) -> Option<[usize; 2]> {
+        let next = (self.selected + 1).checked_rem(self.entries.len())?;
+        self.select(next)
+    }
+
+    /// Select the previous row, wrapping around at the start
+    pub fn select_previous(&mut self) -> Option<[usize; 2]> {
+        let len = self.entries.len();
+        let previous = (self.selected + len.checked_sub(1)?) % len;
+        self.select(previous)
+    }
+
+    /// Returns the area covered by a row
+    pub fn row_area(&self, row: usize) -> Rectangle {
+        let y = self.position.y + (row as u32 * self.row_height) as i32;
+        Rectangle::new(
+            Point::new(self.position.x, y),
+            Size::new(self.width, self.row_height),
+        )
+    }
+
+    /// Draw a single row
+    ///
+    /// Rows that are out of range are ignored.
+    pub fn render_row<D>(&self, target: &mut D, row: usize) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let Some(entry) = self.entries.get(row) else {
+            return Ok(());
+        };
+
+        let area = self.row_area(row);
+        let (background, color) = match self.highlight {
+            Highlight::Tint(color) if row == self.selected => (self.background, Some(color)),
+            Highlight::Invert {
+                background,
+                foreground,
+            } if row == self.selected => (Some(background), Some(foreground)),
+            _ => (self.background, None),
+        };
+        if let Some(background) = background {
+            target.fill_solid(&area, background)?;
+        }
+
+        let icon_height = entry.icon.size().height;
+        let offset = (self.row_height.saturating_sub(icon_height) / 2) as i32;
+        let icon_position = area.top_left + Point::new(0, offset);
+        let icon = Image::new(entry.icon, icon_position, color.unwrap_or(self.color));
+
+        let mut style = self.style;
+        if color.is_some() {
+            style.text_color = color;
+        }
+        IconLabel::new(icon, entry.label, style).draw(target)
+    }
+}
+
+impl<T, C> Widget<C> for Menu<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        (0..self.entries.len()).try_for_each(|row| self.render_row(target, row))
+    }
+
+    fn area(&self) -> Rectangle {
+        Rectangle::new(
+            self.position,
+            Size::new(self.width, self.row_height * self.entries.len() as u32),
+        )
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::AsciiImage;
+    use embedded_graphics::mono_font::{MonoTextStyle, ascii::FONT_4X6};
+
+    #[test]
+    fn test_menu_selection() {
+        let icon = AsciiImage::from_rows(&["##", "##"]).unwrap();
+        let entries = [
+            MenuEntry::new(&icon, ""),
+            MenuEntry::new(&icon, ""),
+            MenuEntry::new(&icon, ""),
+        ];
+        let style = MonoTextStyle::new(&FONT_4X6, BinaryColor::On);
+        let highlight = Highlight::Invert {
+            background: BinaryColor::On,
+            foreground: BinaryColor::Off,
+        };
+        let mut menu = Menu::new(
+            &entries,
+            Point::zero(),
+            4,
+            style,
+            BinaryColor::On,
+            highlight,
+        );
+
+        assert_eq!(menu.area().size, Size::new(4, 18));
+        assert_eq!(menu.select_previous(), Some([0, 2]));
+        assert_eq!(menu.select_next(), Some([2, 0]));
+        assert_eq!(menu.select(0), None);
+        assert_eq!(menu.select(3), None);
+        assert_eq!(
+            menu.row_area(1),
+            Rectangle::new(Point::new(0, 6), Size::new(4, 6))
+        );
+    }
+
+    #[test]
+    fn test_empty_menu_selection() {
+        let entries: [MenuEntry<'_, AsciiImage<'_>>; 0] = [];
+        let style = MonoTextStyle::new(&FONT_4X6, BinaryColor::On);
+        let mut menu = Menu::new(
+            &entries,
+            Point::zero(),
+            4,
+            style,
+            BinaryColor::On,
+            Highlight::Tint(BinaryColor::Off),
+        );
+
+        assert_eq!(menu.select_next(), None);
+        assert_eq!(menu.select_previous(), None);
+    }
+
+    #[test]
+    fn test_menu_render_row() {
+        use embedded_graphics::mock_display::MockDisplay;
+
+        let icon = AsciiImage::from_rows(&["#"]).unwrap();
+        let entries = [MenuEntry::new(&icon, "")];
+        let style = MonoTextStyle::new(&FONT_4X6, BinaryColor::On);
+        let highlight = Highlight::Invert {
+            background: BinaryColor::On,
+            foreground: BinaryColor::Off,
+        };
+        let menu = Menu::new(
+            &entries,
+            Point::zero(),
+            2,
+            style,
+            BinaryColor::On,
+            highlight,
+        )
+        .with_row_height(3);
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        menu.render_row(&mut display, 0).unwrap();
+        display.assert_pattern(&["##", ".#", "##"]);
+    }
+}
@@ -0,0 +1,15 @@
+//! Small drawables built from tinted images.
+
+mod icon_label;
+mod indicator;
+mod list;
+mod menu;
+mod progress;
+mod toggle;
+
+pub use self::icon_label::IconLabel;
+pub use self::indicator::Indicator;
+pub use self::list::{Widget, WidgetList};
+pub use self::menu::{Highlight, Menu, MenuEntry};
+pub use self::progress::ProgressBar;
+pub use self::toggle::Toggle;
@@ -0,0 +1,171 @@
+use super::Widget;
+use crate::{Image, geometry, view::Cropped};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point, Size},
+    primitives::Rectangle,
+};
+
+/// Progress bar revealing a fill image on top of a track image.
+///
+/// The fill image is revealed from the left according to a value between 0
+/// and 100, the rest of the bar shows the track image. Both images have
+/// their own tint color.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::widget::{ProgressBar, Widget};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let track = ImageRaw::<BinaryColor>::new(&[0xff, 0x81, 0xff], 8);
+/// let fill = ImageRaw::<BinaryColor>::new(&[0xff, 0xff, 0xff], 8);
+///
+/// let mut bar = ProgressBar::new(&track, &fill, Point::zero(), Rgb565::WHITE, Rgb565::GREEN);
+/// bar.set_value(50);
+/// bar.render(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProgressBar<'a, T, C> {
+    track: &'a T,
+    fill: &'a T,
+    position: Point,
+    track_color: C,
+    fill_color: C,
+    value: u8,
+    dirty: bool,
+}
+
+impl<'a, T, C> ProgressBar<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create a new, empty progress bar
+    pub const fn new(
+        track: &'a T,
+        fill: &'a T,
+        position: Point,
+        track_color: C,
+        fill_color: C,
+    ) -> Self {
+        Self {
+            track,
+            fill,
+            position,
+            track_color,
+            fill_color,
+            value: 0,
+            dirty: true,
+        }
+    }
+
+    /// Returns the current value
+    pub const fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// Change the value and return the area that needs to be redrawn
+    ///
+    /// Values above 100 are clamped. The returned rectangle only covers the
+    /// columns between the old and the new end of the fill, it's zero sized
+    /// if nothing changed.
+    pub fn set_value(&mut self, value: u8) -> Rectangle {
+        let value = value.min(100);
+        let (old, new) = (self.edge(), self.edge_for(value));
+        self.value = value;
+        if old == new {
+            return Rectangle::zero();
+        }
+
+        self.dirty = true;
+        let height = self.area().size.height;
+        Rectangle::new(
+            self.position + Point::new(old.min(new) as i32, 0),
+            Size::new(old.abs_diff(new), height),
+        )
+    }
+
+    fn edge(&self) -> u32 {
+        self.edge_for(self.value)
+    }
+
+    /// Returns the width of the revealed part of the fill image
+    fn edge_for(&self, value: u8) -> u32 {
+        (u64::from(self.fill.size().width) * u64::from(value) / 100) as u32
+    }
+}
+
+impl<T, C> Widget<C> for ProgressBar<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let edge = self.edge();
+        let fill_area = Rectangle::new(Point::zero(), Size::new(edge, self.fill.size().height));
+        let fill = Cropped::new(self.fill, &fill_area);
+        Image::new(&fill, self.position, self.fill_color).draw(target)?;
+
+        let track_area = Rectangle::new(Point::new(edge as i32, 0), self.track.size());
+        let track = Cropped::new(self.track, &track_area);
+        let position = self.position + Point::new(edge as i32, 0);
+        Image::new(&track, position, self.track_color).draw(target)
+    }
+
+    fn area(&self) -> Rectangle {
+        let track = Image::new(self.track, self.position, self.track_color).bounding_box();
+        let fill = Image::new(self.fill, self.position, self.fill_color).bounding_box();
+        geometry::union(&track, &fill)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay};
+
+    #[test]
+    fn test_progress_bar_reveal() {
+        let track = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+        let fill = ImageRaw::<BinaryColor>::new(&[0xff], 8);
+        let mut bar = ProgressBar::new(
+            &track,
+            &fill,
+            Point::zero(),
+            BinaryColor::Off,
+            BinaryColor::On,
+        );
+
+        assert_eq!(
+            bar.set_value(50),
+            Rectangle::new(Point::zero(), Size::new(4, 1))
+        );
+        assert_eq!(bar.set_value(50), Rectangle::zero());
+        assert_eq!(
+            bar.set_value(200),
+            Rectangle::new(Point::new(4, 0), Size::new(4, 1))
+        );
+        assert_eq!(bar.value(), 100);
+
+        bar.set_value(25);
+        let mut display = MockDisplay::new();
+        bar.render(&mut display).unwrap();
+        display.assert_pattern(&["##......"]);
+    }
+}
@@ -0,0 +1,150 @@
+use super::Widget;
+use crate::{Image, geometry};
+use embedded_graphics::{
+    Drawable,
+    geometry::{Dimensions, OriginDimensions},
+    image::GetPixel,
+    pixelcolor::BinaryColor,
+    prelude::{DrawTarget, PixelColor, Point},
+    primitives::Rectangle,
+};
+
+/// Toggle or checkbox showing one of two images.
+///
+/// Each state has its own image and tint color. Changing the state marks
+/// the widget dirty and reports the area that needs to be redrawn.
+///
+/// ```rust
+/// use embedded_graphics::{image::ImageRaw, pixelcolor::{BinaryColor, Rgb565}, prelude::*};
+/// use embedded_graphics_colorcast::widget::{Toggle, Widget};
+/// # use embedded_graphics::mock_display::MockDisplay as Display;
+///
+/// let mut display: Display<Rgb565> = Display::default();
+/// let on = ImageRaw::<BinaryColor>::new(&[0xff, 0xff], 8);
+/// let off = ImageRaw::<BinaryColor>::new(&[0x81, 0xff], 8);
+///
+/// let mut toggle = Toggle::new(&on, &off, Point::zero(), Rgb565::GREEN, Rgb565::WHITE);
+/// let dirty = toggle.set_state(true);
+/// assert_eq!(dirty.size, Size::new(8, 2));
+/// toggle.render(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Toggle<'a, T, C> {
+    on: &'a T,
+    off: &'a T,
+    position: Point,
+    on_color: C,
+    off_color: C,
+    state: bool,
+    dirty: bool,
+}
+
+impl<'a, T, C> Toggle<'a, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    /// Create a new toggle in the off state
+    pub const fn new(on: &'a T, off: &'a T, position: Point, on_color: C, off_color: C) -> Self {
+        Self {
+            on,
+            off,
+            position,
+            on_color,
+            off_color,
+            state: false,
+            dirty: true,
+        }
+    }
+
+    /// Returns the current state
+    pub const fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Change the state and return the area that needs to be redrawn
+    ///
+    /// The returned rectangle is zero sized if the state didn't change.
+    pub fn set_state(&mut self, state: bool) -> Rectangle {
+        if self.state == state {
+            return Rectangle::zero();
+        }
+        self.state = state;
+        self.dirty = true;
+        self.area()
+    }
+
+    /// Flip the state and return the area that needs to be redrawn
+    pub fn toggle(&mut self) -> Rectangle {
+        self.set_state(!self.state)
+    }
+
+    /// Returns the image for the current state
+    pub fn image(&self) -> Image<'a, T, C> {
+        if self.state {
+            Image::new(self.on, self.position, self.on_color)
+        } else {
+            Image::new(self.off, self.position, self.off_color)
+        }
+    }
+}
+
+impl<T, C> Widget<C> for Toggle<'_, T, C>
+where
+    T: OriginDimensions + GetPixel<Color = BinaryColor>,
+    C: PixelColor,
+{
+    fn render<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.image().draw(target)
+    }
+
+    /// Returns the area covered by both images
+    fn area(&self) -> Rectangle {
+        let on = Image::new(self.on, self.position, self.on_color).bounding_box();
+        let off = Image::new(self.off, self.position, self.off_color).bounding_box();
+        geometry::union(&on, &off)
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::{image::ImageRaw, mock_display::MockDisplay, prelude::Size};
+
+    #[test]
+    fn test_toggle_state() {
+        let on = ImageRaw::<BinaryColor>::new(&[0b1100_0000], 2);
+        let off = ImageRaw::<BinaryColor>::new(&[0b0100_0000, 0b0100_0000], 2);
+        let mut toggle = Toggle::new(
+            &on,
+            &off,
+            Point::new(1, 0),
+            BinaryColor::On,
+            BinaryColor::Off,
+        );
+
+        assert_eq!(toggle.set_state(false), Rectangle::zero());
+        assert_eq!(
+            toggle.toggle(),
+            Rectangle::new(Point::new(1, 0), Size::new(2, 2))
+        );
+        assert!(toggle.state());
+
+        let mut display = MockDisplay::new();
+        toggle.render_dirty(&mut display, None).unwrap();
+        display.assert_pattern(&[" ##"]);
+        assert!(!toggle.is_dirty());
+    }
+}